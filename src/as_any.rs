@@ -14,12 +14,34 @@
 // If not, see <https://opensource.org/licenses/MIT>.
 
 use proc_macro2::TokenStream as TokenStream2;
-use syn::{DeriveInput, Result};
+use syn::{DeriveInput, GenericParam, Result, WherePredicate};
 
 pub(crate) fn inner(input: DeriveInput) -> Result<TokenStream2> {
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
     let ident_name = &input.ident;
 
+    // Conversion into `&dyn Any` requires the type to be `'static`, so generic
+    // types get the implementation only for `'static` parameters
+    let mut generics = input.generics.clone();
+    let bounds = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                Some(parse_quote! { #ident: 'static })
+            }
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                Some(parse_quote! { #lifetime: 'static })
+            }
+            GenericParam::Const(_) => None,
+        })
+        .collect::<Vec<WherePredicate>>();
+    generics.make_where_clause().predicates.extend(bounds);
+    let where_clause = &generics.where_clause;
+
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics ::amplify::AsAny for #ident_name #ty_generics #where_clause {
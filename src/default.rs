@@ -0,0 +1,146 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Expr, Fields, GenericParam, Lit,
+    Meta, MetaNameValue, Result,
+};
+
+const NAME: &str = "default";
+const EXAMPLE: &str = r#"#[default = "Duration::from_secs(30)"]"#;
+
+pub(crate) fn inner(input: DeriveInput) -> Result<TokenStream2> {
+    let value = match input.data {
+        Data::Struct(ref data) => inner_struct(&input, data)?,
+        Data::Enum(ref data) => inner_enum(&input, data)?,
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Deriving `Default` is not supported in unions",
+            ));
+        }
+    };
+
+    // Following `std` derive, each type parameter is required to implement
+    // `Default`
+    let mut generics = input.generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(parse_quote! { ::core::default::Default });
+        }
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let ident_name = &input.ident;
+
+    Ok(quote! {
+        // Deprecated fields must be initialized anyway
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl #impl_generics ::core::default::Default for #ident_name #ty_generics #where_clause {
+            fn default() -> Self {
+                #value
+            }
+        }
+    })
+}
+
+fn inner_struct(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream2> {
+    reject_marker(&input.attrs, "structures")?;
+    fields_value(&data.fields, quote! { Self })
+}
+
+fn inner_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
+    reject_marker(&input.attrs, "enums")?;
+    let mut variants = data
+        .variants
+        .iter()
+        .filter(|variant| variant.attrs.iter().any(|attr| attr.path.is_ident(NAME)));
+    let variant = match (variants.next(), variants.next()) {
+        (Some(variant), None) => variant,
+        (None, _) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Deriving `Default` for enums requires one of the variants to be marked with \
+                 `#[default]` attribute",
+            ));
+        }
+        (Some(_), Some(other)) => {
+            return Err(Error::new_spanned(
+                &other.ident,
+                "`#[default]` attribute may be used only on a single enum variant",
+            ));
+        }
+    };
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident(NAME))
+        .expect("variant is selected by the presence of the attribute");
+    if !attr.tokens.is_empty() {
+        return Err(attr_err!(attr, "enum variant marker must have no value, like `#[default]`"));
+    }
+    let name = &variant.ident;
+    fields_value(&variant.fields, quote! { Self::#name })
+}
+
+/// Rejects `#[default]` attribute given to the type itself
+fn reject_marker(attrs: &[Attribute], kind: &str) -> Result<()> {
+    match attrs.iter().find(|attr| attr.path.is_ident(NAME)) {
+        Some(attr) => Err(attr_err!(
+            attr,
+            (format!("attribute can't be used on {}, only on their fields or variants", kind))
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Constructs value of the fields, using custom initializer expressions given
+/// with `#[default = "expr"]` and `Default::default()` for the rest of fields
+fn fields_value(fields: &Fields, constructor: TokenStream2) -> Result<TokenStream2> {
+    let mut values = Vec::with_capacity(fields.len());
+    for field in fields {
+        let cfg = field.attrs.iter().filter(|attr| attr.path.is_ident("cfg"));
+        let name = field.ident.as_ref().map(|ident| quote! { #ident: });
+        let value = match field.attrs.iter().find(|attr| attr.path.is_ident(NAME)) {
+            Some(attr) => {
+                let expr = field_expr(attr)?;
+                quote_spanned! { attr.span() => #expr }
+            }
+            None => quote_spanned! { field.span() => ::core::default::Default::default() },
+        };
+        values.push(quote! { #( #cfg )* #name #value });
+    }
+    Ok(match fields {
+        Fields::Named(_) => quote! { #constructor { #( #values ),* } },
+        Fields::Unnamed(_) => quote! { #constructor(#( #values ),*) },
+        Fields::Unit => constructor,
+    })
+}
+
+/// Parses initializer expression from `#[default = "expr"]` field attribute
+fn field_expr(attr: &Attribute) -> Result<Expr> {
+    match attr.parse_meta()? {
+        Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(lit), ..
+        }) => lit.parse(),
+        _ => Err(attr_err!(
+            attr,
+            "field default value must be given as a string literal with an expression"
+        )),
+    }
+}
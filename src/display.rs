@@ -21,6 +21,8 @@ use syn::{
     Lit, LitStr, Meta, MetaNameValue, NestedMeta, Path, Result,
 };
 
+use crate::wrapper::get_params;
+
 const NAME: &str = "display";
 const EXAMPLE: &str = r#"#[display("format {} string" | Trait | Type::function)]"#;
 const FIELD_EXAMPLE: &str = r#"#[display(separator = "...")]"#;
@@ -111,7 +113,7 @@ enum Technique {
     FromMethod(Path),
     WithFormat(LitStr, Option<LitStr>),
     DocComments(String),
-    Inner,
+    Inner(Option<LitStr>),
     Lowercase(String),
     Uppercase(String),
 }
@@ -143,8 +145,13 @@ impl Technique {
                         Some(Technique::DocComments(String::new()))
                     }
                     Some(NestedMeta::Meta(Meta::Path(path))) if path.is_ident("inner") => {
-                        Some(Technique::Inner)
+                        Some(Technique::Inner(None))
                     }
+                    Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(field),
+                        ..
+                    }))) if path.is_ident("inner") => Some(Technique::Inner(Some(field.clone()))),
                     Some(NestedMeta::Meta(Meta::Path(path))) if path.is_ident("lowercase") => {
                         Some(Technique::Lowercase(String::new()))
                     }
@@ -218,7 +225,7 @@ impl Technique {
                 }
             }
             Technique::DocComments(doc) => quote! { #doc },
-            Technique::Inner => {
+            Technique::Inner(_) => {
                 if alt {
                     quote! { "{_0:#}" }
                 } else {
@@ -250,7 +257,7 @@ impl Technique {
                 let format = quote_spanned! { span => #doc };
                 Self::impl_format(fields, &format, span)
             }
-            Technique::Inner => {
+            Technique::Inner(_) => {
                 let format = if alt {
                     quote_spanned! { span => "{_0:#}" }
                 } else {
@@ -421,22 +428,23 @@ fn inner_struct(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream2>
         (_, Technique::FromTrait(_)) | (_, Technique::FromMethod(_)) => technique
             .clone()
             .into_token_stream2(&data.fields, input.span(), false),
-        (Fields::Named(fields), Technique::Inner) => {
-            if fields.named.len() != 1 {
-                return Err(attr_err!(
-                    fields.span(),
-                    "display(inner) requires only single field in the structure"
-                ));
-            }
-            let field = fields
-                .named
-                .first()
-                .expect("we just checked that there is a single field")
-                .ident
-                .as_ref()
-                .expect("named fields always have ident with the name");
-            quote_spanned! { field.span() =>
-                write!(f, #tokens_fmt, _0 = self.#field)
+        (_, Technique::Inner(name)) => {
+            let field = match name {
+                Some(name) => inner_field(&data.fields, name)?,
+                None => {
+                    get_params(input)
+                        .map_err(|_| {
+                            attr_err!(
+                                data.fields.span(),
+                                "display(inner) requires only single field in the structure; use \
+                                 `display(inner = \"field\")` to point out the displayed field"
+                            )
+                        })?
+                        .0
+                }
+            };
+            quote_spanned! { data.fields.span() =>
+                ::core::fmt::Display::fmt(&self.#field, f)
             }
         }
         (Fields::Named(fields), _) => {
@@ -530,6 +538,32 @@ fn inner_struct(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream2>
     })
 }
 
+/// Resolves the field named by `display(inner = "field")` argument, which is
+/// either a field name or an index of a tuple struct field
+fn inner_field(fields: &Fields, name: &LitStr) -> Result<TokenStream2> {
+    let value = name.value();
+    let found = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .find(|ident| *ident == value.as_str())
+            .map(|ident| quote! { #ident }),
+        Fields::Unnamed(fields) => value
+            .parse::<usize>()
+            .ok()
+            .filter(|index| *index < fields.unnamed.len())
+            .map(|index| {
+                let index = Index::from(index);
+                quote! { #index }
+            }),
+        Fields::Unit => None,
+    };
+    found.ok_or_else(|| {
+        attr_err!(name, (format!("display(inner) refers to unknown field `{}`", value)))
+    })
+}
+
 fn format_field(field: &syn::Field, str_fmt: &str) -> Result<Option<TokenStream2>> {
     let ident = field.ident.as_ref().unwrap();
     if !has_formatters(ident, str_fmt) {
@@ -565,12 +599,15 @@ fn inner_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
     let mut display = TokenStream2::new();
 
     let global = Technique::from_attrs(&input.attrs, input.span())?;
+    if let Some(Technique::Inner(Some(name))) = &global {
+        return Err(attr_err!(name, "display(inner = \"...\") can be used only with structures"));
+    }
     // Ancient rust versions do not known about `matches!` macro
     #[allow(clippy::match_like_matches_macro)]
     let mut use_global = match global {
-        Some(Technique::Inner) | Some(Technique::Lowercase(_)) | Some(Technique::Uppercase(_)) => {
-            false
-        }
+        Some(Technique::Inner(_)) |
+        Some(Technique::Lowercase(_)) |
+        Some(Technique::Uppercase(_)) => false,
         _ => true,
     };
 
@@ -579,6 +616,12 @@ fn inner_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
         let type_str = format!("{}", type_name);
 
         let mut local = Technique::from_attrs(&v.attrs, v.span())?;
+        if let Some(Technique::Inner(Some(name))) = &local {
+            return Err(attr_err!(
+                name,
+                "display(inner = \"...\") can be used only with structures"
+            ));
+        }
         let mut parent = global.clone();
         let current = local.as_mut().or(parent.as_mut());
         let mut current = current
@@ -603,6 +646,18 @@ fn inner_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
                         *t = Technique::DocComments(String::new());
                         t.apply_docs(&v.attrs);
                         t.fix_fmt();
+                        if let Technique::DocComments(doc) = t {
+                            if doc.is_empty() {
+                                return Err(attr_err!(
+                                    v.ident,
+                                    (format!(
+                                        "variant `{}` must have doc comment to be displayed with \
+                                         `doc_comments`",
+                                        type_str
+                                    ))
+                                ));
+                            }
+                        }
                     }
                     Technique::Lowercase(_) => {
                         *t = Technique::Lowercase(String::new());
@@ -639,7 +694,7 @@ fn inner_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
                 });
             }
             (Fields::Named(fields), Some(tokens_fmt), Some(tokens_alt)) => {
-                if let Some(Technique::Inner) = current {
+                if let Some(Technique::Inner(_)) = current {
                     if fields.named.len() != 1 {
                         return Err(attr_err!(
                             fields.span(),
@@ -731,7 +786,7 @@ fn inner_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
                 }
             }
             (Fields::Unit, Some(tokens_fmt), Some(tokens_alt)) => {
-                if let Some(Technique::Inner) = current {
+                if let Some(Technique::Inner(_)) = current {
                     display.extend(quote_spanned! { v.span() =>
                         Self::#type_name => f.write_str(#type_str),
                     });
@@ -15,19 +15,87 @@
 // If not, see <https://opensource.org/licenses/MIT>.
 
 use proc_macro2::TokenStream as TokenStream2;
-use syn::{DeriveInput, Result};
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Fields, Index, Member, Result};
+
+/// Detects field which is the error source: either marked with `#[source]`
+/// attribute, or the only field of an entity marked with `#[source]`. Fields
+/// marked with `#[from]` are not sources by themselves, since the types they
+/// are converted from are not necessarily errors.
+fn source_member(fields: &Fields, attrs: &[Attribute]) -> Option<Member> {
+    let is_source = |attr: &Attribute| attr.path.is_ident("source");
+    let member = |(index, field): (usize, &syn::Field)| match field.ident {
+        Some(ref ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index {
+            index: index as u32,
+            span: field.span(),
+        }),
+    };
+    fields
+        .iter()
+        .enumerate()
+        .find(|(_, field)| field.attrs.iter().any(is_source))
+        .or_else(|| match fields.len() {
+            1 if attrs.iter().any(is_source) => fields.iter().enumerate().next(),
+            _ => None,
+        })
+        .map(member)
+}
 
 pub(crate) fn inner(input: DeriveInput) -> Result<TokenStream2> {
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let ident_name = &input.ident;
 
+    let source = match input.data {
+        Data::Struct(ref data) => source_member(&data.fields, &input.attrs).map(|member| {
+            quote! {
+                ::core::option::Option::Some(&self.#member as &(dyn ::std::error::Error + 'static))
+            }
+        }),
+        Data::Enum(ref data) => {
+            let arms = data
+                .variants
+                .iter()
+                .filter_map(|variant| {
+                    let member = source_member(&variant.fields, &variant.attrs)?;
+                    let name = &variant.ident;
+                    Some(quote! {
+                        Self::#name { #member: source, .. } => {
+                            ::core::option::Option::Some(source as &(dyn ::std::error::Error + 'static))
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            if arms.is_empty() {
+                None
+            } else {
+                Some(quote! {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        #( #arms )*
+                        _ => ::core::option::Option::None,
+                    }
+                })
+            }
+        }
+        Data::Union(_) => None,
+    };
+    let source = source.map(|source| {
+        quote! {
+            fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+                #source
+            }
+        }
+    });
+
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics ::std::error::Error for #ident_name #ty_generics #where_clause {
+            #source
         }
 
         #[automatically_derived]
-        impl #impl_generics From<#ident_name #ty_generics> for String #where_clause {
+        impl #impl_generics ::core::convert::From<#ident_name #ty_generics> for ::std::string::String #where_clause {
             fn from(err: #ident_name #ty_generics) -> Self {
                 err.to_string()
             }
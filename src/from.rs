@@ -107,6 +107,16 @@ impl InstructionEntity {
         }
     }
 
+    pub fn variant(&self) -> Option<&Ident> {
+        match self {
+            InstructionEntity::Default => None,
+            InstructionEntity::DefaultEnumFields { variant, .. } => Some(variant),
+            InstructionEntity::Unit { variant } |
+            InstructionEntity::Named { variant, .. } |
+            InstructionEntity::Unnamed { variant, .. } => variant.as_ref(),
+        }
+    }
+
     pub fn into_token_stream2(self) -> TokenStream2 {
         match self {
             InstructionEntity::Default => quote! {
@@ -186,7 +196,10 @@ impl InstructionEntry {
         entity: InstructionEntity,
     ) -> Result<Vec<InstructionEntry>> {
         let mut list = Vec::<InstructionEntry>::new();
-        for attr in attrs.iter().filter(|attr| attr.path.is_ident(NAME)) {
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident(NAME) && !is_skip(attr))
+        {
             // #[from]
             if attr.tokens.is_empty() {
                 match (fields.len(), fields.iter().next()) {
@@ -249,6 +262,19 @@ impl InstructionTable {
                 .expect("we know we have at least one item");
             self.push(InstructionEntry::with_type(&field.ty, &entity));
         }
+        // Single-field enum variants without explicit `from` attributes get
+        // conversion from the field type unless they are skipped
+        let explicit = attrs
+            .iter()
+            .chain(fields.iter().flat_map(|field| &field.attrs))
+            .any(|attr| attr.path.is_ident(NAME));
+        if variant.is_some() && fields.len() == 1 && !explicit {
+            let field = fields
+                .into_iter()
+                .next()
+                .expect("we know we have at least one item");
+            self.extend(Some(InstructionEntry::with_type(&field.ty, &entity)))?;
+        }
         Ok(self)
     }
 
@@ -258,12 +284,34 @@ impl InstructionTable {
     where T: IntoIterator<Item = InstructionEntry> {
         let mut count = 0;
         for entry in list {
-            self.0.iter().find(|e| *e == &entry).map_or(Ok(()), |_| {
-                Err(Error::new(
-                    Span::call_site(),
-                    format!("Attribute `#[{}]`: repeated use of type `{}`", NAME, quote! {ty}),
-                ))
-            })?;
+            self.0
+                .iter()
+                .find(|e| *e == &entry)
+                .map_or(Ok(()), |prev| {
+                    let ty = &entry.0;
+                    if let (Some(prev), Some(variant)) = (prev.1.variant(), entry.1.variant()) {
+                        if prev != variant {
+                            return Err(Error::new_spanned(
+                                variant,
+                                format!(
+                                    "variants `{}` and `{}` both convert from type `{}`; use \
+                                     `#[from(skip)]` on one of them",
+                                    prev,
+                                    variant,
+                                    quote! { #ty }
+                                ),
+                            ));
+                        }
+                    }
+                    Err(Error::new(
+                        Span::call_site(),
+                        format!(
+                            "Attribute `#[{}]`: repeated use of type `{}`",
+                            NAME,
+                            quote! { #ty }
+                        ),
+                    ))
+                })?;
             self.0.push(entry);
             count += 1;
         }
@@ -289,6 +337,12 @@ impl InstructionTable {
     }
 }
 
+/// Detects `#[from(skip)]` attribute disabling conversions into enum variant
+fn is_skip(attr: &Attribute) -> bool {
+    attr.parse_args::<Ident>()
+        .map_or(false, |ident| ident == "skip")
+}
+
 pub(crate) fn inner(input: DeriveInput) -> Result<TokenStream2> {
     match input.data {
         Data::Struct(ref data) => inner_struct(&input, data),
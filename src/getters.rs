@@ -18,77 +18,710 @@ use std::convert::TryInto;
 use std::iter::FromIterator;
 
 use amplify_syn::{ArgValue, ArgValueReq, AttrReq, ParametrizedAttr, ValueClass};
-use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use proc_macro2::{Delimiter, Ident, Span, TokenStream as TokenStream2, TokenTree};
 use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    Attribute, Data, DataStruct, DeriveInput, Error, Field, Fields, ImplGenerics, LitStr, Result,
-    TypeGenerics, WhereClause,
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Expr, ExprPath, Field, Fields,
+    Generics, ImplGenerics, Index, Lifetime, Lit, LitStr, Member, Meta, MetaNameValue, NestedMeta,
+    Path, PathArguments, Result, Type, TypeGenerics, TypeParamBound, TypeReference, Variant,
+    Visibility, WhereClause, WherePredicate,
+};
+
+use crate::util::{
+    accessor_args, accessor_prefix, generic_type_args, map_key_value, merge_accessor_args,
+    try_ident, type_last_segment,
 };
 
 pub(crate) fn derive(input: DeriveInput) -> Result<TokenStream2> {
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let struct_name = &input.ident;
 
+    let type_accessor = accessor_args(&input.attrs, true)?;
     let mut global_param = ParametrizedAttr::with("getter", &input.attrs)?;
-    let _ = GetterDerive::try_from(&mut global_param, true)?;
+    merge_accessor_args(&mut global_param.args, &type_accessor);
+    // Enum getters are prefixed with `as_` unless other prefix is given
+    if matches!(input.data, Data::Enum(_)) && !global_param.args.contains_key("prefix") {
+        global_param
+            .args
+            .insert("prefix".to_owned(), ArgValue::from("as_"));
+    }
+    // Case of the serialized field names is applied as `rename_all` rule
+    if global_param
+        .paths
+        .iter()
+        .any(|path| path.is_ident("follow_serde_rename"))
+    {
+        if global_param.args.contains_key("rename_all") {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`follow_serde_rename` getter argument can't be combined with `rename_all`",
+            ));
+        }
+        let rule = serde_rename_all(&input.attrs)?.ok_or_else(|| {
+            Error::new_spanned(
+                &input.ident,
+                "`follow_serde_rename` getter argument requires `#[serde(rename_all = \"...\")]` \
+                 attribute on the type",
+            )
+        })?;
+        global_param
+            .args
+            .insert("rename_all".to_owned(), ArgValue::Literal(Lit::Str(rule)));
+    }
+    let global = GetterDerive::try_from(&mut global_param, AttrLevel::Type)?;
+    if let (false, Some(arg)) = (matches!(input.data, Data::Struct(_)), global.struct_only_arg()) {
+        return Err(Error::new_spanned(
+            &input,
+            format!("`{}` getter argument is supported only in structs", arg),
+        ));
+    }
+    if matches!(input.data, Data::Struct(_)) && global.try_into.is_some() {
+        return Err(Error::new_spanned(
+            &input,
+            "`try_into` getter argument is supported only in enums",
+        ));
+    }
+    if global.repr_c_assert && !is_repr_c(&input.attrs)? {
+        return Err(Error::new_spanned(
+            &input.ident,
+            format!(
+                "`repr_c_assert` getter argument requires `{}` to be `#[repr(C)]`",
+                struct_name
+            ),
+        ));
+    }
 
-    match input.data {
+    let cfg = global.cfg.clone();
+    let output = match input.data {
         Data::Struct(data) => derive_struct_impl(
             data,
             struct_name,
+            &input.vis,
+            global,
             global_param,
-            impl_generics,
-            ty_generics,
-            where_clause,
+            &type_accessor,
+            &input.generics,
         ),
-        Data::Enum(_) => {
-            Err(Error::new_spanned(&input, "Deriving getters is not supported in enums"))
+        Data::Enum(data) => {
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            derive_enum_impl(
+                data,
+                struct_name,
+                global_param,
+                &type_accessor,
+                impl_generics,
+                ty_generics,
+                where_clause,
+            )
         }
         Data::Union(_) => {
             Err(Error::new_spanned(&input, "Deriving getters is not supported in unions"))
         }
+    }?;
+
+    Ok(match cfg {
+        Some(cfg) => with_cfg(&cfg, output),
+        None => output,
+    })
+}
+
+/// Reads `rename_all` rule from serde attributes of the type. When the rule is
+/// different for serialization and deserialization, the serialization one is
+/// used.
+fn serde_rename_all(attrs: &[Attribute]) -> Result<Option<LitStr>> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            _ => continue,
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                })) if path.is_ident("rename_all") => return Ok(Some(lit)),
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("rename_all") => {
+                    let serialize = list.nested.into_iter().find_map(|nested| match nested {
+                        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                            path,
+                            lit: Lit::Str(lit),
+                            ..
+                        })) if path.is_ident("serialize") => Some(lit),
+                        _ => None,
+                    });
+                    if serialize.is_some() {
+                        return Ok(serialize);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Detects whether `#[repr(C)]` is present among the type attributes, possibly
+/// combined with other representation hints like `#[repr(C, packed)]`.
+fn is_repr_c(attrs: &[Attribute]) -> Result<bool> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Meta::List(list) = attr.parse_meta()? {
+            if list.nested.iter().any(
+                |nested| matches!(nested, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C")),
+            ) {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Attaches `#[cfg]` attribute to each of the derived items. Since the items
+/// can't be parsed without full syntax support, they are delimited by the
+/// top-level semicolons and brace-delimited bodies (optionally followed by a
+/// semicolon).
+fn with_cfg(cfg: &Meta, output: TokenStream2) -> TokenStream2 {
+    let mut items = vec![];
+    let mut item = vec![];
+    let mut tokens = output.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let is_end = match token {
+            TokenTree::Punct(ref punct) => punct.as_char() == ';',
+            TokenTree::Group(ref group) => group.delimiter() == Delimiter::Brace,
+            _ => false,
+        };
+        item.push(token);
+        if is_end {
+            if let Some(TokenTree::Punct(punct)) = tokens.peek() {
+                if punct.as_char() == ';' {
+                    item.extend(tokens.next());
+                }
+            }
+            items.push(TokenStream2::from_iter(item.drain(..)));
+        }
+    }
+    if !item.is_empty() {
+        items.push(TokenStream2::from_iter(item));
+    }
+    quote! {
+        #(
+            #[cfg(#cfg)]
+            #items
+        )*
+    }
+}
+
+/// Level at which `#[getter]` attribute arguments are parsed
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum AttrLevel {
+    /// Type-level attribute
+    Type,
+    /// Field- or variant-level attribute
+    Field,
+    /// Field-level attribute merged with the type-level one
+    Merged,
+}
+
+/// Case conversion rules applied to the names of the derived methods
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    fn with(lit: &LitStr) -> Result<RenameRule> {
+        RenameRule::from_name(&lit.value()).ok_or_else(|| {
+            Error::new(lit.span(), format!("unknown `rename_all` rule; {}", RenameRule::SUPPORTED))
+        })
+    }
+
+    const SUPPORTED: &'static str = "the following rules are supported: `lowercase`, `UPPERCASE`, \
+                                     `PascalCase`, `camelCase`, `snake_case`, \
+                                     `SCREAMING_SNAKE_CASE`";
+
+    fn from_name(name: &str) -> Option<RenameRule> {
+        Some(match name {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "PascalCase" => RenameRule::Pascal,
+            "camelCase" => RenameRule::Camel,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            _ => return None,
+        })
+    }
+
+    fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            RenameRule::Lower => name.to_lowercase(),
+            RenameRule::Upper => name.to_uppercase(),
+            RenameRule::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(pos, w)| {
+                    if pos == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            RenameRule::Snake => words
+                .iter()
+                .map(|w| w.to_lowercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            RenameRule::ScreamingSnake => words
+                .iter()
+                .map(|w| w.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+        }
+    }
+}
+
+/// Cargo feature of the crate using the derive, which must be enabled for the
+/// derived code to be compiled. Arguments given without a feature name (or
+/// with an empty one) derive the code unconditionally.
+#[derive(Clone, Default)]
+struct FeatureGate(Option<LitStr>);
+
+impl FeatureGate {
+    fn with(lit: LitStr) -> FeatureGate {
+        if lit.value().is_empty() {
+            FeatureGate(None)
+        } else {
+            FeatureGate(Some(lit))
+        }
+    }
+
+    /// Conditional compilation attribute of the derived item
+    fn cfg_attr(&self) -> TokenStream2 {
+        match self.0 {
+            Some(ref feature) => quote! { #[cfg(feature = #feature)] },
+            None => quote! {},
+        }
+    }
+
+    /// Conditional compilation attribute of the fallback code used when the
+    /// feature is disabled, if there is a feature
+    fn cfg_not_attr(&self) -> Option<TokenStream2> {
+        self.0
+            .as_ref()
+            .map(|feature| quote! { #[cfg(not(feature = #feature))] })
+    }
+}
+
+/// Single step of `rename_all_fields` transformation chain
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum NameTransform {
+    /// Removes the given prefix from the name, if the name starts with it
+    Strip(String),
+    /// Adds the given prefix to the name
+    Add(String),
+    /// Converts case of the name
+    Case(RenameRule),
+}
+
+impl NameTransform {
+    /// Parses `|`-separated list of `strip:<prefix>`, `add:<prefix>` and
+    /// `case:<style>` transformations
+    fn parse_chain(lit: &LitStr) -> Result<Vec<NameTransform>> {
+        let err = |msg: String| Error::new(lit.span(), msg);
+        lit.value()
+            .split('|')
+            .map(|step| match step.trim().split_once(':') {
+                Some(("strip", prefix)) => Ok(NameTransform::Strip(prefix.to_owned())),
+                Some(("add", prefix)) => Ok(NameTransform::Add(prefix.to_owned())),
+                Some(("case", style)) => RenameRule::from_name(style)
+                    .map(NameTransform::Case)
+                    .ok_or_else(|| {
+                        err(format!(
+                            "unknown case style `{}` in `rename_all_fields`; {}",
+                            style,
+                            RenameRule::SUPPORTED
+                        ))
+                    }),
+                _ => Err(err(format!(
+                    "invalid `rename_all_fields` transformation `{}`; expected `strip:<prefix>`, \
+                     `add:<prefix>` or `case:<style>`",
+                    step
+                ))),
+            })
+            .collect()
+    }
+
+    fn apply(&self, name: String) -> String {
+        match self {
+            NameTransform::Strip(prefix) => match name.strip_prefix(prefix.as_str()) {
+                Some(stripped) => stripped.to_owned(),
+                None => name,
+            },
+            NameTransform::Add(prefix) => format!("{}{}", prefix, name),
+            NameTransform::Case(rule) => rule.apply(&name),
+        }
     }
 }
 
 #[derive(Clone)]
 struct GetterDerive {
     pub prefix: LitStr,
+    /// Replace the prefix with the snake-cased name of the field type
+    pub prefix_from_type: bool,
+    pub suffix: LitStr,
     // pub doc: Attribute,
     pub skip: bool,
+    /// Individual methods which are not derived for the field
+    pub skip_main: bool,
+    pub skip_ref: bool,
+    pub skip_mut: bool,
     pub copy: bool,
+    pub clone_bound: bool,
+    /// `Copy` and `Clone` bounds on the field type, which depends on the type
+    /// parameters, required by the main getter
+    pub copy_param_bound: bool,
+    pub clone_param_bound: bool,
+    pub explicit_generics: bool,
+    pub no_doc: bool,
+    pub const_fn: bool,
     pub base: Option<LitStr>,
+    /// Use `_{index}` base names for the unnamed fields without `base_name`
+    pub prefix_numeric_fields: bool,
     pub main: Option<LitStr>,
     pub as_ref: Option<LitStr>,
     pub as_mut: Option<LitStr>,
+    pub as_deref: Option<LitStr>,
+    pub as_str: Option<LitStr>,
+    pub as_slice: Option<LitStr>,
+    pub into: Option<LitStr>,
+    /// Prefix of the enum methods returning the variant value or `Err(self)`
+    pub try_into: Option<LitStr>,
+    pub as_into: Option<LitStr>,
+    /// Suffix of the method projecting pinned struct into pinned field
+    pub pin: Option<LitStr>,
+    /// Suffix of the method returning owned data with `ToOwned`
+    pub to_owned: Option<LitStr>,
+    pub into_type: Option<Type>,
+    /// Target type of `as_ref` method borrowing the field with `AsRef`
+    pub borrow_as: Option<Type>,
+    /// Receiver type of the borrowing getters, replacing `&self`
+    pub self_ty: Option<Type>,
+    pub rename_all: Option<RenameRule>,
+    pub rename_all_fields: Vec<NameTransform>,
+    pub since: Option<LitStr>,
+    /// Configuration predicate under which the methods are excluded from
+    /// coverage
+    pub no_tarpaulin: Option<Meta>,
+    pub is_empty: Option<LitStr>,
+    pub entry: Option<LitStr>,
+    pub set_if_changed: Option<LitStr>,
+    /// Derive setter notifying about the change with `on_change` callback
+    pub observable: bool,
+    /// Callback called with the field name by the observable setters
+    pub on_change: Option<Expr>,
+    pub alias: Option<LitStr>,
+    pub rename: Option<Ident>,
+    /// Keep deprecated methods under the names composed without `rename`
+    pub keep_old: bool,
+    pub deprecated_alias: bool,
+    pub option: bool,
+    pub ret: Option<Type>,
+    pub expr: Option<Expr>,
+    /// `OnceCell` memoizing the value of `expr`
+    pub cached: Option<Expr>,
+    pub validate: bool,
+    pub validate_all: bool,
+    pub diff: bool,
+    pub checksum: bool,
+    pub gen_tests: bool,
+    pub field_enum: bool,
+    pub snapshot: bool,
+    pub const_new: bool,
+    /// Derive constructor preallocating the collection fields
+    pub with_capacity: bool,
+    /// Derive `freeze` method converting the struct into its immutable form
+    pub freeze: bool,
+    /// Statically assert the struct to be `Send + Sync`
+    pub ensure_send_sync: bool,
+    /// Derive `FIELDS` constant listing the getter base names
+    pub field_names: bool,
+    /// Derive empty implementation for unit structs instead of failing
+    pub allow_empty: bool,
+    pub no_getters: bool,
+    pub partial_eq_subset: bool,
+    pub proptest_strategy: Option<FeatureGate>,
+    pub into_parts: bool,
+    /// Derive consuming accessor of the single non-skipped field
+    pub try_into_inner: bool,
+    pub from_parts: bool,
+    pub merge: bool,
+    pub zeroize: Option<FeatureGate>,
+    pub zeroize_all: Option<FeatureGate>,
+    /// Fields with getters, if not all of them
+    pub only: Vec<Member>,
+    /// Require the struct to be `#[repr(C)]`
+    pub repr_c_assert: bool,
+    pub borrow: bool,
+    pub experimental: bool,
+    /// Log each call of the derived methods with `log::trace!`
+    pub trace: Option<FeatureGate>,
+    pub must_use: bool,
+    pub clone_on_copy_warn: bool,
+    /// Inlining attribute of the derived methods
+    pub inline: TokenStream2,
+    /// Lints allowed on the derived methods
+    pub allow: TokenStream2,
+    /// Visibility of the derived methods of each kind, if it differs from
+    /// `pub`
+    pub method_vis: HashMap<&'static str, Visibility>,
+    pub arbitrary_ctor: Option<FeatureGate>,
+    pub trait_name: Option<Ident>,
+    pub checksum_include: bool,
+    pub default_doc: Option<LitStr>,
+    /// Names under which rustdoc search finds the derived methods
+    pub doc_alias: Vec<LitStr>,
+    /// Names of the field attributes copied to the derived methods
+    pub forward_attr: Vec<Ident>,
+    pub validator: Option<LitStr>,
+    pub delegate_deref: Vec<(Ident, Type)>,
+    /// Getters of the field type which are forwarded by the outer struct
+    pub flatten: Vec<(Ident, Type)>,
+    pub flatten_prefix: Option<LitStr>,
+    /// Name of the other field the value of this one is swapped with
+    pub swap: Option<LitStr>,
+    /// Predicate of the `cfg` attribute gating all derived items
+    pub cfg: Option<Meta>,
+    /// Names of `Option` fields holding success value and error
+    pub as_result: Option<(Ident, Ident)>,
+    /// Names of the methods borrowing groups of fields together with the
+    /// field names
+    pub group: Vec<(Ident, Vec<Ident>)>,
 }
 
 impl GetterDerive {
-    #[allow(clippy::blocks_in_if_conditions)]
-    fn try_from(attr: &mut ParametrizedAttr, global: bool) -> Result<GetterDerive> {
+    /// Requirements for the arguments of `#[getter]` attribute used at the
+    /// given level
+    fn arg_reqs(level: AttrLevel) -> HashMap<&'static str, ArgValueReq> {
         let mut map = HashMap::from_iter(vec![
             ("prefix", ArgValueReq::with_default("")),
+            ("prefix_from_type", ArgValueReq::Prohibited),
+            ("suffix", ArgValueReq::with_default("")),
             ("all", ArgValueReq::Prohibited),
             ("as_copy", ArgValueReq::with_default("")),
             ("as_clone", ArgValueReq::with_default("")),
+            ("main", ArgValueReq::with_default("")),
             ("as_ref", ArgValueReq::with_default("")),
             ("as_mut", ArgValueReq::with_default("_mut")),
+            ("as_deref", ArgValueReq::with_default("_deref")),
+            ("as_str", ArgValueReq::with_default("_str")),
+            ("as_slice", ArgValueReq::with_default("_slice")),
+            ("into", ArgValueReq::with_default("into_")),
+            ("try_into", ArgValueReq::with_default("try_into_")),
+            ("as_into", ArgValueReq::with_default("_into")),
+            ("pin", ArgValueReq::with_default("_pin")),
+            ("to_owned", ArgValueReq::with_default("_owned")),
+            ("into_type", ArgValueReq::Optional(ValueClass::str())),
+            ("borrow_as", ArgValueReq::Optional(ValueClass::str())),
+            ("self_ty", ArgValueReq::Optional(ValueClass::str())),
+            ("const", ArgValueReq::Prohibited),
+            ("explicit_generics", ArgValueReq::Prohibited),
+            ("no_doc", ArgValueReq::Prohibited),
+            ("option", ArgValueReq::Prohibited),
+            ("since", ArgValueReq::Optional(ValueClass::str())),
+            ("no_tarpaulin", ArgValueReq::Optional(ValueClass::str())),
+            ("stability", ArgValueReq::Optional(ValueClass::str())),
+            ("trace", ArgValueReq::with_default("")),
+            ("must_use", ArgValueReq::Prohibited),
+            ("clone_on_copy_warn", ArgValueReq::Prohibited),
+            ("borrow", ArgValueReq::Prohibited),
+            ("inline", ArgValueReq::Optional(ValueClass::str())),
+            ("allow", ArgValueReq::Optional(ValueClass::str())),
+            ("forward_attr", ArgValueReq::Optional(ValueClass::str())),
+            ("skip", ArgValueReq::Prohibited),
         ]);
+        for arg in GetterMethod::VIS_ARGS {
+            map.insert(arg, ArgValueReq::Optional(ValueClass::str()));
+        }
 
-        if !global {
-            map.insert("skip", ArgValueReq::Prohibited);
+        if level != AttrLevel::Type {
+            map.insert("expose", ArgValueReq::Prohibited);
+            map.insert("skip_main", ArgValueReq::Prohibited);
+            map.insert("skip_ref", ArgValueReq::Prohibited);
+            map.insert("skip_mut", ArgValueReq::Prohibited);
             map.insert("base_name", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("rename", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("keep_old", ArgValueReq::Prohibited);
+            map.insert("is_empty", ArgValueReq::with_default("_is_empty"));
+            map.insert("entry", ArgValueReq::with_default("_entry"));
+            map.insert("set_if_changed", ArgValueReq::with_default("_set_if_changed"));
+            map.insert("observable", ArgValueReq::Prohibited);
+            map.insert("alias", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("deprecated_alias", ArgValueReq::Prohibited);
+            map.insert("ret", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("expr", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("cached", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("validator", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("checksum_include", ArgValueReq::Prohibited);
+            map.insert("default_doc", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("doc_alias", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("zeroize", ArgValueReq::with_default(""));
+            map.insert("flatten", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("flatten_prefix", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("swap", ArgValueReq::Optional(ValueClass::str()));
         }
+        if level != AttrLevel::Field {
+            map.insert("rename_all", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("follow_serde_rename", ArgValueReq::Prohibited);
+            map.insert("on_change", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("prefix_numeric_fields", ArgValueReq::Prohibited);
+            map.insert("rename_all_fields", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("validate", ArgValueReq::Prohibited);
+            map.insert("validate_all", ArgValueReq::Prohibited);
+            map.insert("diff", ArgValueReq::Prohibited);
+            map.insert("checksum", ArgValueReq::Prohibited);
+            map.insert("gen_tests", ArgValueReq::Prohibited);
+            map.insert("field_enum", ArgValueReq::Prohibited);
+            map.insert("snapshot", ArgValueReq::Prohibited);
+            map.insert("const_new", ArgValueReq::Prohibited);
+            map.insert("with_capacity", ArgValueReq::Prohibited);
+            map.insert("freeze", ArgValueReq::Prohibited);
+            map.insert("ensure_send_sync", ArgValueReq::Prohibited);
+            map.insert("field_names", ArgValueReq::Prohibited);
+            map.insert("allow_empty", ArgValueReq::Prohibited);
+            map.insert("no_getters", ArgValueReq::Prohibited);
+            map.insert("partial_eq_subset", ArgValueReq::Prohibited);
+            map.insert("proptest_strategy", ArgValueReq::with_default(""));
+            map.insert("into_parts", ArgValueReq::Prohibited);
+            map.insert("try_into_inner", ArgValueReq::Prohibited);
+            map.insert("from_parts", ArgValueReq::Prohibited);
+            map.insert("merge", ArgValueReq::Prohibited);
+            map.insert("zeroize_all", ArgValueReq::with_default(""));
+            map.insert("repr_c_assert", ArgValueReq::Prohibited);
+            map.insert("only", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("arbitrary_ctor", ArgValueReq::with_default(""));
+            map.insert("trait", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("delegate_deref", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("as_result", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("group", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("cfg", ArgValueReq::Optional(ValueClass::str()));
+        }
+
+        map
+    }
 
+    #[allow(clippy::blocks_in_conditions)]
+    fn try_from(attr: &mut ParametrizedAttr, level: AttrLevel) -> Result<GetterDerive> {
+        let map = GetterDerive::arg_reqs(level);
+        check_unknown_args(attr, &map, level)?;
+        check_arg_values(attr, &map)?;
+        // Argument spans are lost once the arguments given without values get
+        // their defaults during the check
+        let spans = arg_spans(attr);
+        let span = |arg: &str| spans.get(arg).copied().unwrap_or_else(Span::call_site);
+        if level != AttrLevel::Merged &&
+            spans.contains_key("prefix") &&
+            spans.contains_key("prefix_from_type")
+        {
+            return Err(Error::new(
+                span("prefix_from_type"),
+                "`prefix` and `prefix_from_type` attributes can't be used together",
+            ));
+        }
         attr.check(AttrReq::with(map))?;
 
+        if attr.args.contains_key("deprecated_alias") && !attr.args.contains_key("alias") {
+            return Err(Error::new(
+                span("deprecated_alias"),
+                "`deprecated_alias` attribute requires `alias` to be present",
+            ));
+        }
+
+        if attr.args.contains_key("keep_old") && !attr.args.contains_key("rename") {
+            return Err(Error::new(
+                span("keep_old"),
+                "`keep_old` attribute requires `rename` to be present",
+            ));
+        }
+
+        if attr.args.contains_key("rename") && attr.args.contains_key("base_name") {
+            return Err(Error::new(
+                span("rename"),
+                "`rename` and `base_name` attributes can't be used together",
+            ));
+        }
+
+        if attr.args.contains_key("as_into") != attr.args.contains_key("into_type") {
+            return Err(Error::new(
+                span(if attr.args.contains_key("as_into") {
+                    "as_into"
+                } else {
+                    "into_type"
+                }),
+                "`as_into` and `into_type` attributes must be used together",
+            ));
+        }
+
+        if attr.args.contains_key("const") &&
+            attr.args
+                .get("stability")
+                .and_then(|value| LitStr::try_from(value.clone()).ok())
+                .map(|lit| lit.value() == "experimental")
+                .unwrap_or_default()
+        {
+            return Err(Error::new(
+                span("stability"),
+                "`const` getters can't have `experimental` stability",
+            ));
+        }
+
+        if attr.args.contains_key("const") && attr.args.contains_key("trace") {
+            return Err(Error::new(span("trace"), "`const` getters can't be traced"));
+        }
+
+        if level == AttrLevel::Field &&
+            attr.args.contains_key("skip") &&
+            attr.args.contains_key("expose")
+        {
+            return Err(Error::new(
+                span("expose"),
+                "`skip` and `expose` attributes can't be present together",
+            ));
+        }
+
+        if attr.args.contains_key("flatten_prefix") && !attr.args.contains_key("flatten") {
+            return Err(Error::new(
+                span("flatten_prefix"),
+                "`flatten_prefix` attribute requires `flatten` to be present",
+            ));
+        }
+
+        if attr.args.contains_key("ret") && !attr.args.contains_key("expr") {
+            return Err(Error::new(span("ret"), "`ret` attribute requires `expr` to be present"));
+        }
+        if attr.args.contains_key("cached") && !attr.args.contains_key("expr") {
+            return Err(Error::new(
+                span("cached"),
+                "`cached` attribute requires `expr` to be present",
+            ));
+        }
+
         if attr.args.contains_key("all") {
             if attr.args.contains_key("as_clone") ||
                 attr.args.contains_key("as_ref") ||
                 attr.args.contains_key("as_mut")
             {
                 return Err(Error::new(
-                    Span::call_site(),
+                    span("all"),
                     "`all` attribute can't be combined with other",
                 ));
             }
@@ -102,15 +735,26 @@ impl GetterDerive {
 
         if attr.args.contains_key("as_clone") && attr.args.contains_key("as_copy") {
             return Err(Error::new(
-                Span::call_site(),
+                span("as_copy"),
                 "`as_clone` and `as_copy` attributes can't be present together",
             ));
         }
 
+        if attr.args.contains_key("main") &&
+            (attr.args.contains_key("as_clone") || attr.args.contains_key("as_copy"))
+        {
+            return Err(Error::new(
+                span("main"),
+                "`main` attribute can't be combined with `as_clone` or `as_copy`",
+            ));
+        }
+
         // If we have to return copy or a clone of value and did not explicitly
         // specified different prefix for borrowing accessor, we need not to derive it
         // since we will have a naming conflict
-        if (attr.args.contains_key("as_clone") || attr.args.contains_key("as_copy")) &&
+        if (attr.args.contains_key("as_clone") ||
+            attr.args.contains_key("as_copy") ||
+            attr.args.contains_key("main")) &&
             attr.args
                 .get("as_ref")
                 .map(|a| {
@@ -125,15 +769,50 @@ impl GetterDerive {
             attr.args.remove("as_ref");
         }
 
-        // If we are not provided with any options, default to deriving borrows
-        if !(attr.args.contains_key("as_clone") ||
-            attr.args.contains_key("as_copy") ||
-            attr.args.contains_key("as_ref") ||
-            attr.args.contains_key("as_mut"))
+        // If we are not provided with any options, default to deriving borrows.
+        // Field-level attributes must not do that, since they inherit the
+        // methods from the type level
+        if level == AttrLevel::Type &&
+            !(attr.args.contains_key("as_clone") ||
+                attr.args.contains_key("as_copy") ||
+                attr.args.contains_key("main") ||
+                attr.args.contains_key("as_ref"))
         {
             attr.args.insert("as_ref".to_owned(), ArgValue::from(""));
         }
 
+        // Field-level attributes may add `const` to the methods inherited from the
+        // type level, so we check it only once the attributes are merged
+        if level != AttrLevel::Field &&
+            attr.args.contains_key("const") &&
+            (!attr.args.contains_key("as_copy") ||
+                [
+                    "as_clone", "main", "as_ref", "as_mut", "as_deref", "as_str", "as_slice",
+                    "into", "try_into", "as_into", "pin", "to_owned",
+                ]
+                .iter()
+                .any(|arg| attr.args.contains_key(*arg)))
+        {
+            return Err(Error::new(
+                span("const"),
+                "`const` attribute requires `as_copy` and can't be combined with other getter \
+                 methods, which can't be `const`",
+            ));
+        }
+
+        // Mutable borrow of the field would allow to move it out of the pinned
+        // struct
+        if level != AttrLevel::Field &&
+            attr.args.contains_key("pin") &&
+            attr.args.contains_key("as_mut")
+        {
+            return Err(Error::new(
+                span("pin"),
+                "`pin` attribute can't be combined with `as_mut`, which allows moving the pinned \
+                 field",
+            ));
+        }
+
         Ok(GetterDerive {
             prefix: attr
                 .args
@@ -141,8 +820,24 @@ impl GetterDerive {
                 .map(|a| a.clone().try_into())
                 .transpose()?
                 .unwrap_or_else(|| LitStr::new("", Span::call_site())),
-            skip: attr.args.get("skip").is_some(),
+            prefix_from_type: attr.args.contains_key("prefix_from_type"),
+            suffix: attr
+                .args
+                .get("suffix")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .unwrap_or_else(|| LitStr::new("", Span::call_site())),
+            skip: attr.args.contains_key("skip"),
+            skip_main: attr.args.contains_key("skip_main"),
+            skip_ref: attr.args.contains_key("skip_ref"),
+            skip_mut: attr.args.contains_key("skip_mut"),
             copy: attr.args.contains_key("as_copy"),
+            clone_bound: attr.args.contains_key("main"),
+            copy_param_bound: false,
+            clone_param_bound: false,
+            explicit_generics: attr.args.contains_key("explicit_generics"),
+            no_doc: attr.args.contains_key("no_doc"),
+            const_fn: attr.args.contains_key("const"),
             base: attr
                 .args
                 .get("base_name")
@@ -152,6 +847,7 @@ impl GetterDerive {
                 .args
                 .get("as_copy")
                 .or_else(|| attr.args.get("as_clone"))
+                .or_else(|| attr.args.get("main"))
                 .map(|a| a.clone().try_into())
                 .transpose()?,
             as_ref: attr
@@ -164,226 +860,3905 @@ impl GetterDerive {
                 .get("as_mut")
                 .map(|a| a.clone().try_into())
                 .transpose()?,
-        })
-    }
-}
-
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
-enum GetterMethod {
-    Main { copy: bool },
-    AsRef,
-    AsMut,
-}
-
-impl GetterMethod {
-    fn doc_phrase(&self) -> &'static str {
-        match self {
-            GetterMethod::Main { copy: true } => "returning copy of",
-            GetterMethod::Main { copy: false } => "cloning",
-            GetterMethod::AsRef => "borrowing",
-            GetterMethod::AsMut => "returning mutable borrow of",
-        }
-    }
-
-    fn mut_prefix(&self) -> TokenStream2 {
-        match self {
-            GetterMethod::Main { copy: true } => quote! {},
-            GetterMethod::Main { copy: false } => quote! {},
-            GetterMethod::AsRef => quote! {},
-            GetterMethod::AsMut => quote! { mut },
-        }
-    }
-
-    fn ret_prefix(&self) -> TokenStream2 {
-        match self {
-            GetterMethod::Main { copy: true } => quote! {},
-            GetterMethod::Main { copy: false } => quote! {},
-            GetterMethod::AsRef => quote! { & },
-            GetterMethod::AsMut => quote! { &mut },
-        }
-    }
-
-    fn ret_suffix(&self) -> TokenStream2 {
-        match self {
-            GetterMethod::Main { copy: true } => quote! {},
-            GetterMethod::Main { copy: false } => quote! { .clone() },
-            GetterMethod::AsRef => quote! {},
-            GetterMethod::AsMut => quote! {},
-        }
-    }
-}
-
-impl GetterDerive {
-    pub fn all_methods(&self) -> Vec<GetterMethod> {
-        let mut methods = Vec::with_capacity(3);
-        if self.main.is_some() {
-            methods.push(GetterMethod::Main { copy: self.copy });
-        }
-        if self.as_ref.is_some() {
-            methods.push(GetterMethod::AsRef);
-        }
-        if self.as_mut.is_some() {
-            methods.push(GetterMethod::AsMut);
-        }
-        methods
-    }
-
-    pub fn getter_fn_ident(
-        &self,
-        method: GetterMethod,
-        field_name: Option<&Ident>,
-        span: Span,
-    ) -> Result<Ident> {
-        let base_string = self
-            .base
-            .as_ref()
-            .map(LitStr::value)
-            .or_else(|| field_name.map(Ident::to_string))
-            .ok_or_else(|| {
-                Error::new(
-                    span,
-                    "Unnamed fields must be equipped with `#[getter(base_name = \"name\"]` \
-                     attribute",
-                )
-            })?;
-
-        let name_lit = match method {
-            GetterMethod::Main { .. } => &self.main,
-            GetterMethod::AsRef => &self.as_ref,
-            GetterMethod::AsMut => &self.as_mut,
-        }
-        .clone()
-        .expect("Internal inconsistency in getter derivation macro implementation");
-
-        let s = format!("{}{}{}", self.prefix.value(), base_string, name_lit.value());
-
-        Ok(Ident::new(&s, span))
-    }
-
-    pub fn getter_fn_doc(
-        &self,
-        method: GetterMethod,
-        struct_name: &Ident,
-        field_name: Option<&Ident>,
-        field_index: usize,
-        field_doc: Option<&Attribute>,
-    ) -> TokenStream2 {
-        let fn_doc = format!(
-            "Method {} [`{}::{}`] field.\n",
-            method.doc_phrase(),
-            struct_name,
-            field_name
-                .map(Ident::to_string)
-                .unwrap_or_else(|| field_index.to_string())
-        );
-
-        if let Some(field_doc) = field_doc {
-            quote! {
-                #[doc = #fn_doc]
-                #field_doc
-            }
-        } else {
-            quote! {
-                #[doc = #fn_doc]
-            }
-        }
-    }
-}
-
-fn derive_struct_impl(
-    data: DataStruct,
-    struct_name: &Ident,
-    global_param: ParametrizedAttr,
-    impl_generics: ImplGenerics,
-    ty_generics: TypeGenerics,
-    where_clause: Option<&WhereClause>,
-) -> Result<TokenStream2> {
-    let mut methods = Vec::with_capacity(data.fields.len());
-    match data.fields {
-        Fields::Named(ref fields) => {
-            for (index, field) in fields.named.iter().enumerate() {
-                methods.extend(derive_field_methods(field, index, struct_name, &global_param)?)
-            }
-        }
-        Fields::Unnamed(_) => {
-            return Err(Error::new(
-                Span::call_site(),
-                "Deriving getters is not supported for tuple-bases structs",
-            ));
-        }
-        Fields::Unit => {
-            return Err(Error::new(
-                Span::call_site(),
-                "Deriving getters is meaningless for unit structs",
-            ));
-        }
-    };
-
-    Ok(quote! {
-        #[automatically_derived]
+            as_deref: attr
+                .args
+                .get("as_deref")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            as_str: attr
+                .args
+                .get("as_str")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            as_slice: attr
+                .args
+                .get("as_slice")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            into: attr
+                .args
+                .get("into")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            try_into: attr
+                .args
+                .get("try_into")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            as_into: attr
+                .args
+                .get("as_into")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            pin: attr
+                .args
+                .get("pin")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            to_owned: attr
+                .args
+                .get("to_owned")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            into_type: attr
+                .args
+                .get("into_type")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| lit.parse())
+                .transpose()?,
+            borrow_as: attr
+                .args
+                .get("borrow_as")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| lit.parse())
+                .transpose()?,
+            self_ty: attr
+                .args
+                .get("self_ty")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| self_ty_arg(&lit))
+                .transpose()?,
+            rename_all: attr
+                .args
+                .get("rename_all")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .as_ref()
+                .map(RenameRule::with)
+                .transpose()?,
+            rename_all_fields: attr
+                .args
+                .get("rename_all_fields")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .as_ref()
+                .map(NameTransform::parse_chain)
+                .transpose()?
+                .unwrap_or_default(),
+            since: attr
+                .args
+                .get("since")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            no_tarpaulin: attr
+                .args
+                .get("no_tarpaulin")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| cfg_predicate("no_tarpaulin", &lit))
+                .transpose()?,
+            is_empty: attr
+                .args
+                .get("is_empty")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            entry: attr
+                .args
+                .get("entry")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            observable: attr.args.contains_key("observable"),
+            on_change: attr
+                .args
+                .get("on_change")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| lit.parse())
+                .transpose()?,
+            set_if_changed: attr
+                .args
+                .get("set_if_changed")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            alias: attr
+                .args
+                .get("alias")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            deprecated_alias: attr.args.contains_key("deprecated_alias"),
+            keep_old: attr.args.contains_key("keep_old"),
+            rename: attr
+                .args
+                .get("rename")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| ident_arg("rename", &lit))
+                .transpose()?,
+            option: attr.args.contains_key("option"),
+            ret: attr
+                .args
+                .get("ret")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| lit.parse())
+                .transpose()?,
+            expr: attr
+                .args
+                .get("expr")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| lit.parse())
+                .transpose()?,
+            cached: attr
+                .args
+                .get("cached")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| lit.parse())
+                .transpose()?,
+            validate: attr.args.contains_key("validate"),
+            validate_all: attr.args.contains_key("validate_all"),
+            diff: attr.args.contains_key("diff"),
+            checksum: attr.args.contains_key("checksum"),
+            gen_tests: attr.args.contains_key("gen_tests"),
+            field_enum: attr.args.contains_key("field_enum"),
+            snapshot: attr.args.contains_key("snapshot"),
+            const_new: attr.args.contains_key("const_new"),
+            with_capacity: attr.args.contains_key("with_capacity"),
+            freeze: attr.args.contains_key("freeze"),
+            ensure_send_sync: attr.args.contains_key("ensure_send_sync"),
+            field_names: attr.args.contains_key("field_names"),
+            allow_empty: attr.args.contains_key("allow_empty"),
+            no_getters: attr.args.contains_key("no_getters"),
+            partial_eq_subset: attr.args.contains_key("partial_eq_subset"),
+            proptest_strategy: attr
+                .args
+                .get("proptest_strategy")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(FeatureGate::with),
+            into_parts: attr.args.contains_key("into_parts"),
+            try_into_inner: attr.args.contains_key("try_into_inner"),
+            from_parts: attr.args.contains_key("from_parts"),
+            merge: attr.args.contains_key("merge"),
+            zeroize: attr
+                .args
+                .get("zeroize")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(FeatureGate::with),
+            zeroize_all: attr
+                .args
+                .get("zeroize_all")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(FeatureGate::with),
+            repr_c_assert: attr.args.contains_key("repr_c_assert"),
+            only: attr
+                .args
+                .get("only")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| parse_list("only", "field names", &lit))
+                .transpose()?
+                .unwrap_or_default(),
+            prefix_numeric_fields: attr.args.contains_key("prefix_numeric_fields"),
+            borrow: attr.args.contains_key("borrow"),
+            must_use: attr.args.contains_key("must_use"),
+            clone_on_copy_warn: attr.args.contains_key("clone_on_copy_warn"),
+            trace: attr
+                .args
+                .get("trace")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(FeatureGate::with),
+            experimental: attr
+                .args
+                .get("stability")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| match lit.value().as_str() {
+                    "stable" => Ok(false),
+                    "experimental" => Ok(true),
+                    _ => Err(Error::new(
+                        lit.span(),
+                        "`stability` getter argument must be either `stable` or `experimental`",
+                    )),
+                })
+                .transpose()?
+                .unwrap_or_default(),
+            inline: attr
+                .args
+                .get("inline")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| match lit.value().as_str() {
+                    "none" => Ok(quote! {}),
+                    "inline" => Ok(quote! { #[inline] }),
+                    "always" => Ok(quote! { #[inline(always)] }),
+                    _ => Err(Error::new(
+                        lit.span(),
+                        "`inline` getter argument must be one of `none`, `inline` or `always`",
+                    )),
+                })
+                .transpose()?
+                .unwrap_or_else(|| quote! { #[inline] }),
+            allow: attr
+                .args
+                .get("allow")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| parse_allow_lints(&lit))
+                .transpose()?
+                .map(|lints| quote! { #[allow(#lints)] })
+                .unwrap_or_default(),
+            method_vis: GetterMethod::VIS_ARGS
+                .iter()
+                .filter_map(|arg| attr.args.get(*arg).map(|value| (*arg, value)))
+                .map(|(arg, value)| {
+                    let lit: LitStr = value.clone().try_into()?;
+                    let vis = lit.parse().map_err(|_| {
+                        Error::new(
+                            lit.span(),
+                            format!(
+                                "`{}` getter argument must be a visibility, like `pub` or \
+                                 `pub(crate)`",
+                                arg
+                            ),
+                        )
+                    })?;
+                    Ok((arg, vis))
+                })
+                .collect::<Result<_>>()?,
+            arbitrary_ctor: attr
+                .args
+                .get("arbitrary_ctor")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(FeatureGate::with),
+            trait_name: attr
+                .args
+                .get("trait")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| ident_arg("trait", &lit))
+                .transpose()?,
+            checksum_include: attr.args.contains_key("checksum_include"),
+            default_doc: attr
+                .args
+                .get("default_doc")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            doc_alias: attr
+                .args
+                .get("doc_alias")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| parse_doc_aliases(&lit))
+                .transpose()?
+                .unwrap_or_default(),
+            forward_attr: attr
+                .args
+                .get("forward_attr")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| parse_list("forward_attr", "attribute names", &lit))
+                .transpose()?
+                .unwrap_or_default(),
+            delegate_deref: attr
+                .args
+                .get("delegate_deref")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| parse_delegates("delegate_deref", &lit))
+                .transpose()?
+                .unwrap_or_default(),
+            flatten: attr
+                .args
+                .get("flatten")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| parse_delegates("flatten", &lit))
+                .transpose()?
+                .unwrap_or_default(),
+            flatten_prefix: attr
+                .args
+                .get("flatten_prefix")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            swap: attr
+                .args
+                .get("swap")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            cfg: attr
+                .args
+                .get("cfg")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| cfg_predicate("cfg", &lit))
+                .transpose()?,
+            as_result: attr
+                .args
+                .get("as_result")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| parse_result_fields(&lit))
+                .transpose()?,
+            group: attr
+                .args
+                .get("group")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .map(|lit: LitStr| parse_groups(&lit))
+                .transpose()?
+                .unwrap_or_default(),
+            validator: attr
+                .args
+                .get("validator")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+enum GetterMethod {
+    Main { copy: bool },
+    AsRef,
+    AsMut,
+    AsDeref,
+    AsStr,
+    AsSlice,
+    Into,
+    AsInto,
+    Pin,
+    ToOwned,
+}
+
+impl GetterMethod {
+    /// Names of the arguments defining visibility of all methods and of each
+    /// method kind
+    const VIS_ARGS: [&'static str; 11] = [
+        "vis",
+        "main_vis",
+        "ref_vis",
+        "mut_vis",
+        "deref_vis",
+        "str_vis",
+        "slice_vis",
+        "into_vis",
+        "as_into_vis",
+        "pin_vis",
+        "owned_vis",
+    ];
+
+    /// Name of the argument defining visibility of the method
+    fn vis_arg(&self) -> &'static str {
+        match self {
+            GetterMethod::Main { .. } => "main_vis",
+            GetterMethod::AsRef => "ref_vis",
+            GetterMethod::AsMut => "mut_vis",
+            GetterMethod::AsDeref => "deref_vis",
+            GetterMethod::AsStr => "str_vis",
+            GetterMethod::AsSlice => "slice_vis",
+            GetterMethod::Into => "into_vis",
+            GetterMethod::AsInto => "as_into_vis",
+            GetterMethod::Pin => "pin_vis",
+            GetterMethod::ToOwned => "owned_vis",
+        }
+    }
+
+    fn doc_phrase(&self) -> &'static str {
+        match self {
+            GetterMethod::Main { copy: true } => "returning copy of",
+            GetterMethod::Main { copy: false } => "cloning",
+            GetterMethod::AsRef => "borrowing",
+            GetterMethod::AsMut => "returning mutable borrow of",
+            GetterMethod::AsDeref => "dereferencing",
+            GetterMethod::AsStr => "returning string slice of",
+            GetterMethod::AsSlice => "returning slice of",
+            GetterMethod::Into => "consuming the value and returning",
+            GetterMethod::AsInto => "converting",
+            GetterMethod::Pin => "returning pinned reference to",
+            GetterMethod::ToOwned => "returning owned copy of",
+        }
+    }
+
+    /// Detects methods returning data borrowed from `self`
+    fn is_borrowing(&self) -> bool {
+        matches!(
+            self,
+            GetterMethod::AsRef |
+                GetterMethod::AsMut |
+                GetterMethod::AsDeref |
+                GetterMethod::AsStr |
+                GetterMethod::AsSlice |
+                GetterMethod::Pin
+        )
+    }
+
+    fn receiver(&self, lifetime: &TokenStream2) -> TokenStream2 {
+        match self {
+            GetterMethod::Main { copy: true } => quote! { &self },
+            GetterMethod::Main { copy: false } => quote! { &self },
+            GetterMethod::AsRef => quote! { &#lifetime self },
+            GetterMethod::AsMut => quote! { &#lifetime mut self },
+            GetterMethod::AsDeref | GetterMethod::AsStr | GetterMethod::AsSlice => {
+                quote! { &#lifetime self }
+            }
+            GetterMethod::Into => quote! { self },
+            GetterMethod::AsInto | GetterMethod::ToOwned => quote! { &self },
+            GetterMethod::Pin => quote! { self: ::core::pin::Pin<&#lifetime Self> },
+        }
+    }
+
+    fn ret_value(&self, value: TokenStream2) -> TokenStream2 {
+        match self {
+            GetterMethod::Main { copy: true } => value,
+            GetterMethod::Main { copy: false } => quote! { ::core::clone::Clone::clone(&#value) },
+            GetterMethod::AsRef => quote! { &#value },
+            GetterMethod::AsMut => quote! { &mut #value },
+            GetterMethod::AsDeref | GetterMethod::AsStr => {
+                quote! { ::core::ops::Deref::deref(&#value) }
+            }
+            GetterMethod::AsSlice => quote! { &#value[..] },
+            GetterMethod::Into => value,
+            GetterMethod::AsInto => quote! {
+                ::core::convert::Into::into(::core::clone::Clone::clone(&#value))
+            },
+            GetterMethod::ToOwned => quote! { ::std::borrow::ToOwned::to_owned(&#value) },
+            GetterMethod::Pin => {
+                unreachable!("Internal inconsistency in getter derivation macro implementation")
+            }
+        }
+    }
+
+    fn ret_type(&self, ty: &Type, lifetime: &TokenStream2) -> TokenStream2 {
+        match self {
+            GetterMethod::Main { .. } | GetterMethod::Into => quote! { #ty },
+            GetterMethod::AsRef => quote! { &#lifetime #ty },
+            GetterMethod::AsMut => quote! { &#lifetime mut #ty },
+            GetterMethod::AsDeref => quote! { &#lifetime <#ty as ::core::ops::Deref>::Target },
+            GetterMethod::AsStr => quote! { &#lifetime str },
+            GetterMethod::AsSlice => {
+                let elem = slice_elem(ty)
+                    .expect("Internal inconsistency in getter derivation macro implementation");
+                quote! { &#lifetime [#elem] }
+            }
+            GetterMethod::Pin => quote! { ::core::pin::Pin<&#lifetime #ty> },
+            // Borrowed data of reference fields is turned into owned one
+            GetterMethod::ToOwned => {
+                let ty = owned_source(ty);
+                quote! { <#ty as ::std::borrow::ToOwned>::Owned }
+            }
+            GetterMethod::AsInto => {
+                unreachable!("Internal inconsistency in getter derivation macro implementation")
+            }
+        }
+    }
+
+    fn where_bound(&self, ty: &Type) -> TokenStream2 {
+        match self {
+            GetterMethod::AsDeref => quote! { where #ty: ::core::ops::Deref },
+            GetterMethod::ToOwned => {
+                let ty = owned_source(ty);
+                quote! { where #ty: ::std::borrow::ToOwned }
+            }
+            _ => quote! {},
+        }
+    }
+}
+
+impl GetterDerive {
+    /// Returns name of the first present argument which is not supported
+    /// outside of structs
+    pub fn struct_only_arg(&self) -> Option<&'static str> {
+        [
+            ("is_empty", self.is_empty.is_some()),
+            ("entry", self.entry.is_some()),
+            ("set_if_changed", self.set_if_changed.is_some()),
+            ("observable", self.observable),
+            ("on_change", self.on_change.is_some()),
+            ("alias", self.alias.is_some()),
+            ("keep_old", self.keep_old),
+            ("option", self.option),
+            ("ret", self.ret.is_some()),
+            ("expr", self.expr.is_some()),
+            ("cached", self.cached.is_some()),
+            ("validate", self.validate),
+            ("validate_all", self.validate_all),
+            ("validator", self.validator.is_some()),
+            ("diff", self.diff),
+            ("checksum", self.checksum),
+            ("gen_tests", self.gen_tests),
+            ("field_enum", self.field_enum),
+            ("snapshot", self.snapshot),
+            ("const_new", self.const_new),
+            ("with_capacity", self.with_capacity),
+            ("freeze", self.freeze),
+            ("ensure_send_sync", self.ensure_send_sync),
+            ("field_names", self.field_names),
+            ("no_getters", self.no_getters),
+            ("partial_eq_subset", self.partial_eq_subset),
+            ("proptest_strategy", self.proptest_strategy.is_some()),
+            ("into_parts", self.into_parts),
+            ("try_into_inner", self.try_into_inner),
+            ("from_parts", self.from_parts),
+            ("merge", self.merge),
+            ("zeroize", self.zeroize.is_some()),
+            ("zeroize_all", self.zeroize_all.is_some()),
+            ("repr_c_assert", self.repr_c_assert),
+            ("only", !self.only.is_empty()),
+            ("prefix_numeric_fields", self.prefix_numeric_fields),
+            ("prefix_from_type", self.prefix_from_type),
+            ("borrow", self.borrow),
+            ("clone_on_copy_warn", self.clone_on_copy_warn),
+            ("stability", self.experimental),
+            ("trace", self.trace.is_some()),
+            ("arbitrary_ctor", self.arbitrary_ctor.is_some()),
+            ("trait", self.trait_name.is_some()),
+            ("checksum_include", self.checksum_include),
+            ("default_doc", self.default_doc.is_some()),
+            ("delegate_deref", !self.delegate_deref.is_empty()),
+            ("flatten", !self.flatten.is_empty()),
+            ("swap", self.swap.is_some()),
+            ("as_result", self.as_result.is_some()),
+            ("group", !self.group.is_empty()),
+            ("borrow_as", self.borrow_as.is_some()),
+            ("pin", self.pin.is_some()),
+            ("self_ty", self.self_ty.is_some()),
+        ]
+        .into_iter()
+        .find_map(|(arg, present)| if present { Some(arg) } else { None })
+    }
+
+    pub fn all_methods(&self) -> Vec<GetterMethod> {
+        let mut methods = Vec::with_capacity(3);
+        if self.main.is_some() && !self.skip_main {
+            methods.push(GetterMethod::Main { copy: self.copy });
+        }
+        if self.as_ref.is_some() && !self.skip_ref {
+            methods.push(GetterMethod::AsRef);
+        }
+        if self.as_mut.is_some() && !self.skip_mut {
+            methods.push(GetterMethod::AsMut);
+        }
+        if self.as_deref.is_some() {
+            methods.push(GetterMethod::AsDeref);
+        }
+        if self.as_str.is_some() {
+            methods.push(GetterMethod::AsStr);
+        }
+        if self.as_slice.is_some() {
+            methods.push(GetterMethod::AsSlice);
+        }
+        if self.into.is_some() {
+            methods.push(GetterMethod::Into);
+        }
+        if self.as_into.is_some() {
+            methods.push(GetterMethod::AsInto);
+        }
+        if self.pin.is_some() {
+            methods.push(GetterMethod::Pin);
+        }
+        if self.to_owned.is_some() {
+            methods.push(GetterMethod::ToOwned);
+        }
+        methods
+    }
+
+    pub fn getter_fn_ident(
+        &self,
+        method: GetterMethod,
+        field_name: Option<&Ident>,
+        span: Span,
+    ) -> Result<Ident> {
+        let name_lit = match method {
+            GetterMethod::Main { .. } => &self.main,
+            GetterMethod::AsRef => &self.as_ref,
+            GetterMethod::AsMut => &self.as_mut,
+            GetterMethod::AsDeref => &self.as_deref,
+            GetterMethod::AsStr => &self.as_str,
+            GetterMethod::AsSlice => &self.as_slice,
+            GetterMethod::Into => &self.into,
+            GetterMethod::AsInto => &self.as_into,
+            GetterMethod::Pin => &self.pin,
+            GetterMethod::ToOwned => &self.to_owned,
+        }
+        .clone()
+        .expect("Internal inconsistency in getter derivation macro implementation");
+
+        match (method, &self.rename) {
+            // Custom name replaces prefix and base name; method suffixes (or
+            // the prefix of consuming getter) are still applied to it
+            (GetterMethod::Into, Some(rename)) => {
+                fn_ident(&format!("{}{}", name_lit.value(), rename.unraw()), span)
+            }
+            (_, Some(rename)) => fn_ident(&format!("{}{}", rename.unraw(), name_lit.value()), span),
+            // Name of consuming getter is defined by its own prefix, which
+            // replaces the type-level one
+            (GetterMethod::Into, None) => {
+                let mut getter = self.clone();
+                getter.prefix = name_lit;
+                getter.compose_fn_ident("", field_name, span)
+            }
+            (_, None) => self.compose_fn_ident(&name_lit.value(), field_name, span),
+        }
+    }
+
+    /// Composes method name out of the prefix, base name, suffix and the
+    /// provided method-specific suffix
+    pub fn compose_fn_ident(
+        &self,
+        suffix: &str,
+        field_name: Option<&Ident>,
+        span: Span,
+    ) -> Result<Ident> {
+        let base_string = self.base_string(field_name, span)?;
+
+        let mut s =
+            format!("{}{}{}{}", self.prefix.value(), base_string, self.suffix.value(), suffix);
+        if let Some(rule) = self.rename_all {
+            s = rule.apply(&s);
+        }
+        for transform in &self.rename_all_fields {
+            s = transform.apply(s);
+        }
+
+        let ident = try_ident(&s, span);
+        if ident.is_none() {
+            // Pointing to the argument which breaks the identifier, if it can
+            // be detected
+            let is_invalid = |part: String| syn::parse_str::<Ident>(&part).is_err();
+            let error_span = if is_invalid(format!("{}a", self.prefix.value())) {
+                self.prefix.span()
+            } else if let Some(base) = self
+                .base
+                .as_ref()
+                .filter(|b| is_invalid(format!("a{}", b.value())))
+            {
+                base.span()
+            } else if is_invalid(format!("a{}", self.suffix.value())) {
+                self.suffix.span()
+            } else {
+                span
+            };
+            return Err(Error::new(
+                error_span,
+                format!(
+                    "getter method name `{}` composed out of prefix `{}`, base name `{}` and \
+                     suffix `{}{}` is not a valid Rust identifier",
+                    s,
+                    self.prefix.value(),
+                    base_string,
+                    self.suffix.value(),
+                    suffix
+                ),
+            ));
+        }
+
+        Ok(ident.expect("identifier validity is checked above"))
+    }
+
+    /// Returns base name for the methods: either the one given by `base_name`
+    /// argument, or the field name
+    pub fn base_string(&self, field_name: Option<&Ident>, span: Span) -> Result<String> {
+        self.base
+            .as_ref()
+            .map(LitStr::value)
+            .or_else(|| field_name.map(|ident| ident.unraw().to_string()))
+            .ok_or_else(|| {
+                Error::new(
+                    span,
+                    "Unnamed fields must be equipped with `#[getter(base_name = \"name\"]` \
+                     attribute",
+                )
+            })
+    }
+
+    pub fn getter_variant_doc(
+        &self,
+        method: GetterMethod,
+        enum_name: &Ident,
+        variant_name: &Ident,
+        variant_doc: &[&Attribute],
+    ) -> TokenStream2 {
+        let fn_doc = format!(
+            "Method {} inner data of [`{}::{}`] variant, if the value matches it.\n",
+            method.doc_phrase(),
+            enum_name,
+            variant_name
+        );
+
+        let fn_doc = self.synthesized_doc(fn_doc);
+        let since_doc = self.since_doc();
+        let doc_alias = &self.doc_alias;
+        quote! {
+            #fn_doc
+            #( #variant_doc )*
+            #since_doc
+            #( #[doc(alias = #doc_alias)] )*
+        }
+    }
+
+    pub fn getter_fn_doc(
+        &self,
+        method: GetterMethod,
+        struct_name: &Ident,
+        field_name: Option<&Ident>,
+        field_index: usize,
+        field_doc: &[&Attribute],
+    ) -> TokenStream2 {
+        let fn_doc = format!(
+            "Method {} [`{}::{}`] field.\n",
+            method.doc_phrase(),
+            struct_name,
+            field_name
+                .map(Ident::to_string)
+                .unwrap_or_else(|| field_index.to_string())
+        );
+
+        let fn_doc = self.synthesized_doc(fn_doc);
+        let default_doc = self.default_doc();
+        let since_doc = self.since_doc();
+        let doc_alias = &self.doc_alias;
+        quote! {
+            #fn_doc
+            #( #field_doc )*
+            #default_doc
+            #since_doc
+            #( #[doc(alias = #doc_alias)] )*
+        }
+    }
+
+    /// Name of the getter argument defining the method
+    fn method_arg(&self, method: GetterMethod) -> &'static str {
+        match method {
+            GetterMethod::Main { copy: true } => "as_copy",
+            GetterMethod::Main { copy: false } if self.clone_bound => "main",
+            GetterMethod::Main { copy: false } => "as_clone",
+            GetterMethod::AsRef => "as_ref",
+            GetterMethod::AsMut => "as_mut",
+            GetterMethod::AsDeref => "as_deref",
+            GetterMethod::AsStr => "as_str",
+            GetterMethod::AsSlice => "as_slice",
+            GetterMethod::Into => "into",
+            GetterMethod::AsInto => "as_into",
+            GetterMethod::Pin => "pin",
+            GetterMethod::ToOwned => "to_owned",
+        }
+    }
+
+    /// Return type of the derived method for a field (or variant data) of
+    /// the given type
+    fn ret_type(&self, method: GetterMethod, ty: &Type) -> TokenStream2 {
+        match (method, &self.into_type, &self.borrow_as) {
+            (GetterMethod::AsInto, Some(target), _) => quote! { #target },
+            (GetterMethod::AsRef, _, Some(target)) => {
+                let lifetime = self.lifetime(method);
+                quote! { &#lifetime #target }
+            }
+            _ => method.ret_type(ty, &self.lifetime(method)),
+        }
+    }
+
+    /// Explicit lifetime of the borrow returned by the derived method, if
+    /// `explicit_generics` is used
+    fn lifetime(&self, method: GetterMethod) -> TokenStream2 {
+        if self.explicit_generics && method.is_borrowing() {
+            quote! { 'getter }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// Generic parameters of the derived method
+    fn fn_generics(&self, method: GetterMethod) -> TokenStream2 {
+        if self.explicit_generics && method.is_borrowing() {
+            quote! { <'getter> }
+        } else {
+            quote! {}
+        }
+    }
+
+    fn receiver(&self, method: GetterMethod) -> TokenStream2 {
+        match (method, &self.self_ty) {
+            (
+                GetterMethod::AsRef |
+                GetterMethod::AsDeref |
+                GetterMethod::AsStr |
+                GetterMethod::AsSlice,
+                Some(Type::Reference(self_ty)),
+            ) => {
+                let mut self_ty = self_ty.clone();
+                if self.explicit_generics && self_ty.lifetime.is_none() {
+                    self_ty.lifetime = Some(Lifetime::new("'getter", Span::call_site()));
+                }
+                quote! { self: #self_ty }
+            }
+            _ => method.receiver(&self.lifetime(method)),
+        }
+    }
+
+    /// Where clause required by the derived method
+    fn where_bound(&self, method: GetterMethod, ty: &Type) -> TokenStream2 {
+        match (method, &self.into_type, &self.borrow_as) {
+            (GetterMethod::AsInto, Some(target), _) => quote! {
+                where #ty: ::core::convert::Into<#target> + ::core::clone::Clone
+            },
+            (GetterMethod::AsRef, _, Some(target)) => quote! {
+                where #ty: ::core::convert::AsRef<#target>
+            },
+            (GetterMethod::Main { copy: true }, ..) if self.copy_param_bound => quote! {
+                where #ty: ::core::marker::Copy
+            },
+            // Explicit bound pointing to the field type gives better diagnostics
+            // for the types which can't be cloned
+            (GetterMethod::Main { copy: false }, ..) if self.clone_bound => quote_spanned! {
+                ty.span() => where #ty: ::core::clone::Clone
+            },
+            (GetterMethod::Main { copy: false }, ..) if self.clone_param_bound => quote! {
+                where #ty: ::core::clone::Clone
+            },
+            _ => method.where_bound(ty),
+        }
+    }
+
+    /// Visibility of the derived method
+    fn vis(&self, method: GetterMethod) -> TokenStream2 {
+        match self
+            .method_vis
+            .get(method.vis_arg())
+            .or_else(|| self.method_vis.get("vis"))
+        {
+            Some(vis) => vis.to_token_stream(),
+            None => quote! { pub },
+        }
+    }
+
+    /// `const` qualifier for the derived method
+    fn constness(&self, method: GetterMethod) -> TokenStream2 {
+        match method {
+            GetterMethod::Main { copy: true } if self.const_fn => quote! { const },
+            _ => quote! {},
+        }
+    }
+
+    /// Body of the derived method, which for `experimental` getters prints
+    /// warning on the first call, and for `trace` getters logs each call (if
+    /// the feature of the user crate they are gated on is enabled)
+    fn guarded_body(
+        &self,
+        struct_name: &Ident,
+        field_name: &str,
+        fn_name: &Ident,
+        body: TokenStream2,
+    ) -> TokenStream2 {
+        let trace = match self.trace {
+            Some(ref feature) => {
+                let message = format!("accessing {}::{}", struct_name, field_name);
+                let cfg = feature.cfg_attr();
+                quote! {
+                    #cfg
+                    ::log::trace!(#message);
+                }
+            }
+            None => quote! {},
+        };
+        if !self.experimental {
+            return quote! { #trace #body };
+        }
+        let warning = format!(
+            "warning: `{}::{}` is an experimental API and may change or be removed",
+            struct_name, fn_name
+        );
+        quote! {
+            #trace
+            static WARNING: ::std::sync::Once = ::std::sync::Once::new();
+            WARNING.call_once(|| ::std::eprintln!(#warning));
+            #body
+        }
+    }
+
+    /// Attribute excluding derived method from coverage reports when the
+    /// `no_tarpaulin` predicate holds
+    fn coverage_attr(&self) -> TokenStream2 {
+        match self.no_tarpaulin {
+            Some(ref predicate) => quote! { #[cfg_attr(#predicate, coverage(off))] },
+            None => quote! {},
+        }
+    }
+
+    /// Doc lines telling the version since which the getter is available
+    fn since_doc(&self) -> TokenStream2 {
+        match self.since {
+            Some(ref version) => {
+                let line = format!("Available since version {}.", version.value());
+                quote! {
+                    #[doc = ""]
+                    #[doc = #line]
+                }
+            }
+            None => quote! {},
+        }
+    }
+
+    /// Synthesized doc line describing the derived method, unless `no_doc`
+    /// is used
+    fn synthesized_doc(&self, fn_doc: String) -> TokenStream2 {
+        if self.no_doc {
+            quote! {}
+        } else {
+            quote! { #[doc = #fn_doc] }
+        }
+    }
+
+    /// Doc lines telling the default value of the field, if it is provided
+    /// with `default_doc`
+    fn default_doc(&self) -> TokenStream2 {
+        match self.default_doc {
+            Some(ref value) => {
+                let line = format!("Default: `{}`.", value.value());
+                quote! {
+                    #[doc = ""]
+                    #[doc = #line]
+                }
+            }
+            None => quote! {},
+        }
+    }
+}
+
+/// Struct field together with its getter configuration, combined from the
+/// field- and type-level `#[getter]` attributes
+struct GetterField<'a> {
+    index: usize,
+    field: &'a Field,
+    getter: GetterDerive,
+}
+
+impl<'a> GetterField<'a> {
+    /// Field name as it is used in the docs and in the generated code
+    /// reporting field names (field index for tuple structs)
+    fn name_string(&self) -> String {
+        self.field
+            .ident
+            .as_ref()
+            .map(Ident::to_string)
+            .unwrap_or_else(|| self.index.to_string())
+    }
+
+    /// Field name without `r#` prefix of raw identifiers, used to compose
+    /// names of the derived methods
+    fn unraw_name(&self) -> String {
+        self.field
+            .ident
+            .as_ref()
+            .map(|ident| ident.unraw().to_string())
+            .unwrap_or_else(|| self.index.to_string())
+    }
+
+    /// Tokens accessing the field in `self.#access` expressions
+    fn access(&self) -> TokenStream2 {
+        match self.field.ident {
+            Some(ref ident) => quote! { #ident },
+            None => Index::from(self.index).to_token_stream(),
+        }
+    }
+
+    /// `deprecated` attributes of the field, which are forwarded to the derived
+    /// methods
+    fn deprecated_attrs(&self) -> TokenStream2 {
+        let attrs = self
+            .field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("deprecated"));
+        quote! { #( #attrs )* }
+    }
+
+    /// Field attributes listed in `forward_attr` argument, which are copied to
+    /// the derived methods
+    fn forwarded_attrs(&self) -> TokenStream2 {
+        let attrs = self.field.attrs.iter().filter(|attr| {
+            self.getter
+                .forward_attr
+                .iter()
+                .any(|name| attr.path.is_ident(name))
+        });
+        quote! { #( #attrs )* }
+    }
+
+    /// Conditional compilation attributes of the field, which are forwarded
+    /// to the derived methods
+    fn cfg_attrs(&self) -> TokenStream2 {
+        let attrs = self
+            .field
+            .attrs
+            .iter()
+            .filter(|attr| is_forwarded_cfg(attr));
+        quote! { #( #attrs )* }
+    }
+}
+
+/// Detects `cfg` attributes and `cfg_attr` attributes conditionally applying
+/// only attributes which are valid on methods (other attributes, like the
+/// ones used by serde, are specific to fields)
+fn is_forwarded_cfg(attr: &Attribute) -> bool {
+    const FORWARDED: [&str; 8] =
+        ["cfg", "doc", "deprecated", "must_use", "allow", "warn", "deny", "forbid"];
+
+    if attr.path.is_ident("cfg") {
+        return true;
+    }
+    if !attr.path.is_ident("cfg_attr") {
+        return false;
+    }
+    match attr.parse_meta() {
+        Ok(Meta::List(list)) => list.nested.iter().skip(1).all(|nested| {
+            matches!(
+                nested,
+                NestedMeta::Meta(meta) if FORWARDED.iter().any(|name| meta.path().is_ident(name))
+            )
+        }),
+        _ => false,
+    }
+}
+
+fn derive_struct_impl(
+    data: DataStruct,
+    struct_name: &Ident,
+    vis: &Visibility,
+    global: GetterDerive,
+    global_param: ParametrizedAttr,
+    type_accessor: &HashMap<String, ArgValue>,
+    generics: &Generics,
+) -> Result<TokenStream2> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    if let (Fields::Unit, true) = (&data.fields, global.allow_empty) {
+        return Ok(quote! {
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {}
+        });
+    }
+    if let Fields::Unit = data.fields {
+        return Err(Error::new(
+            Span::call_site(),
+            "Deriving getters is meaningless for unit structs",
+        ));
+    }
+
+    if let Some(member) = global.only.iter().find(|member| {
+        !data
+            .fields
+            .iter()
+            .enumerate()
+            .any(|(index, field)| is_member(member, index, field))
+    }) {
+        return Err(Error::new(
+            member.span(),
+            format!(
+                "`only` getter argument refers to unknown field `{}`",
+                member.to_token_stream()
+            ),
+        ));
+    }
+
+    let mut inherited = None;
+    let mut fields = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let mut getter =
+                local_getter(&field.attrs, &global_param, type_accessor, &mut inherited)?;
+            // Fields not listed in `only` are skipped
+            if !global.only.is_empty() &&
+                !global
+                    .only
+                    .iter()
+                    .any(|member| is_member(member, index, field))
+            {
+                getter.skip = true;
+            }
+            if getter.try_into.is_some() {
+                return Err(Error::new_spanned(
+                    field,
+                    "`try_into` getter argument is supported only in enums",
+                ));
+            }
+            if getter.prefix_numeric_fields && field.ident.is_none() && getter.base.is_none() {
+                getter.base = Some(LitStr::new(&format!("_{}", index), field.span()));
+            }
+            if getter.prefix_from_type {
+                let segment = type_last_segment(&field.ty).ok_or_else(|| {
+                    Error::new_spanned(
+                        &field.ty,
+                        "`prefix_from_type` getter argument requires field of a path type, like \
+                         `Config`",
+                    )
+                })?;
+                let prefix = RenameRule::Snake.apply(&segment.ident.unraw().to_string());
+                getter.prefix = LitStr::new(&format!("{}_", prefix), segment.ident.span());
+            }
+            Ok(GetterField {
+                index,
+                field,
+                getter,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Consuming getters of multi-field structs would silently drop all other
+    // fields
+    if let (true, Some(field)) = (
+        fields.len() > 1,
+        fields
+            .iter()
+            .find(|f| !f.getter.skip && f.getter.into.is_some()),
+    ) {
+        return Err(Error::new(
+            field.field.span(),
+            "`into` getter argument requires struct to have a single field",
+        ));
+    }
+
+    let mut items = vec![];
+    let type_params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    for field in &fields {
+        if let Some(check) = derive_clone_on_copy_check(field, struct_name, &type_params) {
+            items.push(check);
+        }
+    }
+
+    let mut borrowed = fields.iter().filter(|f| !f.getter.skip && f.getter.borrow);
+    match (borrowed.next(), borrowed.next()) {
+        (Some(field), Some(other)) => {
+            return Err(Error::new(
+                other.field.span(),
+                format!(
+                    "`borrow` getter argument can be used with a single field only, but it is \
+                     already used with field `{}`",
+                    field.name_string()
+                ),
+            ));
+        }
+        (Some(field), None) => items.push(derive_borrow_impls(field, struct_name, generics)),
+        (None, _) => {}
+    }
+
+    add_param_bounds(generics, &mut fields);
+
+    // Methods with the same name are reported at the field causing the clash
+    // instead of compiler's duplicate definition error. Fields with different
+    // `cfg` attributes may legitimately share method names.
+    let mut getter_fns = Vec::<GetterFn>::with_capacity(fields.len());
+    let mut fn_fields = HashMap::<(String, String), (String, &'static str)>::new();
+    for field in &fields {
+        for getter_fn in derive_field_methods(field, struct_name)? {
+            let key = (getter_fn.name.to_string(), getter_fn.cfg.to_string());
+            if let Some((other, other_arg)) =
+                fn_fields.insert(key, (field.name_string(), getter_fn.arg))
+            {
+                let msg = if other == field.name_string() {
+                    format!(
+                        "getter methods derived for field `{}` with `{}` and `{}` arguments have \
+                         the same name `{}`; use different suffixes to distinguish them",
+                        other, other_arg, getter_fn.arg, getter_fn.name
+                    )
+                } else {
+                    format!(
+                        "getter method `{}` derived for field `{}` clashes with the method of the \
+                         same name derived for field `{}`",
+                        getter_fn.name,
+                        field.name_string(),
+                        other
+                    )
+                };
+                return Err(Error::new(getter_fn.span, msg));
+            }
+            getter_fns.push(getter_fn);
+        }
+    }
+
+    if let (true, Some(arg)) = (
+        global.no_getters,
+        [("trait", global.trait_name.is_some()), ("gen_tests", global.gen_tests)]
+            .into_iter()
+            .find_map(|(arg, present)| if present { Some(arg) } else { None }),
+    ) {
+        return Err(Error::new(
+            Span::call_site(),
+            format!(
+                "`{}` getter argument requires getter methods, which are disabled with \
+                 `no_getters`",
+                arg
+            ),
+        ));
+    }
+
+    let coverage = global.coverage_attr();
+    let mut methods = vec![];
+    let mut trait_impl = quote! {};
+    match global.trait_name {
+        None => methods.extend(getter_fns.iter().map(GetterFn::inherent)),
+        Some(ref trait_name) => {
+            if let Some(field) = fields.iter().find(|f| !f.getter.method_vis.is_empty()) {
+                return Err(Error::new(
+                    field.field.span(),
+                    "per-method visibility arguments can't be used together with `trait`, since \
+                     trait methods have visibility of the trait itself",
+                ));
+            }
+            if let Some(field) = fields.iter().find(|f| f.getter.const_fn) {
+                return Err(Error::new(
+                    field.field.span(),
+                    "`const` getter argument can't be used together with `trait`, since trait \
+                     methods can't be `const`",
+                ));
+            }
+            let trait_doc = format!("Accessors for the fields of [`{}`] type.", struct_name);
+            let declarations = getter_fns.iter().map(GetterFn::trait_item);
+            items.push(quote! {
+                #[doc = #trait_doc]
+                #vis trait #trait_name #impl_generics #where_clause {
+                    #( #declarations )*
+                }
+            });
+            let implementations = getter_fns.iter().map(GetterFn::trait_impl_item);
+            trait_impl = quote! {
+                #[automatically_derived]
+                impl #impl_generics #trait_name #ty_generics for #struct_name #ty_generics
+                    #where_clause
+                {
+                    #( #implementations )*
+                }
+            };
+        }
+    }
+    if global.validate {
+        let (error_type, validate) = derive_validate(&fields, struct_name)?;
+        items.push(error_type);
+        methods.push(quote! { #coverage #validate });
+    }
+    if global.validate_all {
+        let validate_all = derive_validate_all(&fields, struct_name)?;
+        methods.push(quote! { #coverage #validate_all });
+    }
+    if let (false, Some(validator)) = (
+        global.validate || global.validate_all,
+        fields.iter().find_map(|f| f.getter.validator.as_ref()),
+    ) {
+        return Err(Error::new(
+            validator.span(),
+            "`validator` getter argument requires either `#[getter(validate)]` or \
+             `#[getter(validate_all)]` attribute on the struct",
+        ));
+    }
+
+    if global.field_enum {
+        items.push(derive_field_enum(&fields, struct_name)?);
+    }
+
+    if global.diff {
+        let diff = derive_diff(&fields, struct_name)?;
+        methods.push(quote! { #coverage #diff });
+    }
+
+    if global.partial_eq_subset {
+        let eq_public = derive_eq_public(&fields, struct_name);
+        methods.push(quote! { #coverage #eq_public });
+    }
+
+    if global.merge {
+        let merge = derive_merge(&fields, struct_name)?;
+        methods.push(quote! { #coverage #merge });
+    }
+
+    for field in &fields {
+        if let Some(ref other) = field.getter.swap {
+            let swap = derive_swap(field, &fields, other, struct_name)?;
+            methods.push(quote! { #coverage #swap });
+        }
+    }
+
+    if global.zeroize_all.is_some() || fields.iter().any(|f| f.getter.zeroize.is_some()) {
+        let zeroize = derive_zeroize(&fields, struct_name);
+        methods.push(quote! { #coverage #zeroize });
+    }
+
+    if global.into_parts {
+        let into_parts = derive_into_parts(&fields, struct_name)?;
+        methods.push(quote! { #coverage #into_parts });
+    }
+
+    if global.try_into_inner {
+        let into_inner = derive_try_into_inner(&fields, struct_name, global.validate)?;
+        methods.push(quote! { #coverage #into_inner });
+    }
+
+    if global.from_parts {
+        let from_parts = derive_from_parts(&fields, struct_name)?;
+        methods.push(quote! { #coverage #from_parts });
+    }
+
+    if global.const_new {
+        let new = derive_const_new(&fields, &data.fields, struct_name)?;
+        methods.push(quote! { #coverage #new });
+    }
+
+    if global.with_capacity {
+        let with_capacity = derive_with_capacity(&data.fields, struct_name);
+        methods.push(quote! { #coverage #with_capacity });
+    }
+
+    if global.freeze {
+        let (frozen, freeze) = derive_freeze(&data.fields, struct_name, vis, generics);
+        items.push(frozen);
+        methods.push(quote! { #coverage #freeze });
+    }
+
+    if global.field_names {
+        let field_names = derive_field_names(&fields, struct_name)?;
+        methods.push(field_names);
+    }
+
+    if global.snapshot {
+        let snapshot = derive_snapshot(&fields, struct_name)?;
+        methods.push(quote! { #coverage #snapshot });
+    }
+
+    match (global.checksum, fields.iter().find(|f| f.getter.checksum_include)) {
+        (true, None) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`checksum` getter argument requires at least one field to be marked with \
+                 `#[getter(checksum_include)]`",
+            ));
+        }
+        (false, Some(field)) => {
+            return Err(Error::new(
+                field.field.span(),
+                "`checksum_include` getter argument requires `#[getter(checksum)]` attribute on \
+                 the struct",
+            ));
+        }
+        (true, Some(_)) => {
+            let checksum = derive_checksum(&fields, struct_name);
+            methods.push(quote! { #coverage #checksum });
+        }
+        (false, None) => {}
+    }
+
+    if global.gen_tests {
+        if !impl_generics.to_token_stream().is_empty() {
+            return Err(Error::new(
+                Span::call_site(),
+                "`gen_tests` getter argument is not supported for generic structs",
+            ));
+        }
+        items.push(derive_tests(&fields, struct_name, global.trait_name.as_ref())?);
+    }
+
+    if global.ensure_send_sync {
+        if !generics.params.is_empty() {
+            return Err(Error::new(
+                Span::call_site(),
+                "`ensure_send_sync` getter argument is not supported for generic structs",
+            ));
+        }
+        items.push(quote_spanned! { struct_name.span() =>
+            const _: fn() = || {
+                fn assert_send_sync<T: ?Sized + ::core::marker::Send + ::core::marker::Sync>() {}
+                assert_send_sync::<#struct_name>();
+            };
+        });
+    }
+
+    if let Some(ref feature) = global.arbitrary_ctor {
+        let ctor = derive_arbitrary_ctor(&data.fields, struct_name);
+        let cfg = feature.cfg_attr();
+        items.push(quote! {
+            #cfg
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                #coverage
+                #ctor
+            }
+        });
+    }
+
+    if let Some((ref value, ref error)) = global.as_result {
+        let result = derive_as_result(&fields, struct_name, value, error)?;
+        methods.push(quote! { #coverage #result });
+    }
+
+    for (name, members) in &global.group {
+        let group = derive_group(&fields, struct_name, name, members)?;
+        methods.push(quote! { #coverage #group });
+    }
+
+    if let Some(ref feature) = global.proptest_strategy {
+        let strategy = derive_proptest_strategy(&data.fields, struct_name);
+        let cfg = feature.cfg_attr();
+        items.push(quote! {
+            #cfg
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                #coverage
+                #strategy
+            }
+        });
+    }
+
+    for (name, ty) in &global.delegate_deref {
+        let doc = format!(
+            "Method delegating to `{}` method of the type [`{}`] dereferences to.",
+            name, struct_name
+        );
+        let inline = &global.inline;
+        methods.push(quote_spanned! { name.span() =>
+            #[doc = #doc]
+            #inline
+            #coverage
+            pub fn #name(&self) -> #ty {
+                ::core::ops::Deref::deref(self).#name()
+            }
+        });
+    }
+
+    Ok(quote! {
+        #( #items )*
+
+        #[automatically_derived]
         impl #impl_generics #struct_name #ty_generics #where_clause {
             #( #methods )*
         }
+
+        #trait_impl
+    })
+}
+
+/// Generates `Borrow` and `BorrowMut` implementations for the field with
+/// `borrow` getter argument
+fn derive_borrow_impls(
+    getter_field: &GetterField,
+    struct_name: &Ident,
+    generics: &Generics,
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let field = getter_field.field;
+    let ty = &field.ty;
+    let access = getter_field.access();
+    let coverage = getter_field.getter.coverage_attr();
+    let cfg = field.attrs.iter().filter(|attr| attr.path.is_ident("cfg"));
+    let cfg = quote! { #( #cfg )* };
+
+    quote_spanned! { field.span() =>
+        #cfg
+        #[automatically_derived]
+        impl #impl_generics ::core::borrow::Borrow<#ty> for #struct_name #ty_generics #where_clause {
+            #coverage
+            #[inline]
+            fn borrow(&self) -> &#ty {
+                &self.#access
+            }
+        }
+
+        #cfg
+        #[automatically_derived]
+        impl #impl_generics ::core::borrow::BorrowMut<#ty> for #struct_name #ty_generics
+            #where_clause
+        {
+            #coverage
+            #[inline]
+            fn borrow_mut(&mut self) -> &mut #ty {
+                &mut self.#access
+            }
+        }
+    }
+}
+
+/// Generates hidden check emitting deprecation warning if a field with cloning
+/// getter is of `Copy` type. Since it can't be detected by the macro, the check
+/// relies on method resolution preferring the methods not requiring autoref
+/// (i.e. implemented for `Probe<T: Copy>` rather than for `&Probe<T>`). Types
+/// depending on the struct type parameters are not checked.
+fn derive_clone_on_copy_check(
+    field: &GetterField,
+    struct_name: &Ident,
+    type_params: &[Ident],
+) -> Option<TokenStream2> {
+    let getter = &field.getter;
+    let ty = &field.field.ty;
+    if !getter.clone_on_copy_warn ||
+        getter.skip ||
+        getter.no_getters ||
+        getter.expr.is_some() ||
+        !getter
+            .all_methods()
+            .contains(&GetterMethod::Main { copy: false }) ||
+        mentions_idents(ty.to_token_stream(), type_params)
+    {
+        return None;
+    }
+
+    let note = format!(
+        "field `{}::{}` is of `Copy` type, so its getter should use `as_copy` instead of cloning",
+        struct_name,
+        field.name_string()
+    );
+    let cfg = field.cfg_attrs();
+    // The call must be spanned at the field, since the warnings are not
+    // reported for the code coming from the macro
+    let call = quote_spanned! { ty.span() =>
+        (&Probe::<#ty>(::core::marker::PhantomData)).clone_on_copy()
+    };
+    Some(quote! {
+        #cfg
+        const _: () = {
+            struct Probe<T>(::core::marker::PhantomData<T>);
+            trait CopyProbe {
+                #[deprecated(note = #note)]
+                fn clone_on_copy(&self) {}
+            }
+            impl<T: ::core::marker::Copy> CopyProbe for Probe<T> {}
+            trait CloneProbe {
+                fn clone_on_copy(&self) {}
+            }
+            impl<T> CloneProbe for &Probe<T> {}
+            #[allow(dead_code, clippy::needless_borrow)]
+            fn check() {
+                #call;
+            }
+        };
+    })
+}
+
+/// Requests `Copy` and `Clone` bounds required by `as_copy`, `as_clone` and
+/// `main` getters for the fields which types depend on the struct type
+/// parameters, skipping the bounds already given in the struct declaration.
+/// The bounds are put on the getters themselves, so the other methods are
+/// available for any type parameters.
+fn add_param_bounds(generics: &Generics, fields: &mut [GetterField]) {
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    for field in fields
+        .iter_mut()
+        .filter(|f| !f.getter.skip && f.getter.expr.is_none())
+    {
+        let ty = &field.field.ty;
+        if !mentions_idents(ty.to_token_stream(), &params) {
+            continue;
+        }
+        for method in field.getter.all_methods() {
+            match method {
+                GetterMethod::Main { copy: true } => {
+                    field.getter.copy_param_bound = !has_bound(generics, ty, "Copy")
+                }
+                GetterMethod::Main { copy: false } => {
+                    field.getter.clone_param_bound = !has_bound(generics, ty, "Clone")
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Detects whether generics already bound the type with the trait of the
+/// given name (or with `Copy` when `Clone` is requested, since it implies
+/// `Clone`), either in the type parameter declaration or in where clause
+fn has_bound(generics: &Generics, ty: &Type, name: &str) -> bool {
+    let ty_string = ty.to_token_stream().to_string();
+    let matches_name = |bound: &TypeParamBound| match bound {
+        TypeParamBound::Trait(bound) => bound
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == name || (name == "Clone" && segment.ident == "Copy"))
+            .unwrap_or_default(),
+        TypeParamBound::Lifetime(_) => false,
+    };
+    let in_params = generics
+        .type_params()
+        .filter(|param| param.ident == ty_string)
+        .any(|param| param.bounds.iter().any(matches_name));
+    let in_where = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates)
+        .any(|predicate| match predicate {
+            WherePredicate::Type(predicate) => {
+                predicate.bounded_ty.to_token_stream().to_string() == ty_string &&
+                    predicate.bounds.iter().any(matches_name)
+            }
+            _ => false,
+        });
+    in_params || in_where
+}
+
+/// Detects whether the token stream contains any of the given identifiers
+fn mentions_idents(tokens: TokenStream2, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => mentions_idents(group.stream(), idents),
+        _ => false,
+    })
+}
+
+/// Generates `{Struct}Field` enum with a variant for each of the non-skipped
+/// fields
+fn derive_field_enum(fields: &[GetterField], struct_name: &Ident) -> Result<TokenStream2> {
+    let enum_name = Ident::new(&format!("{}Field", struct_name), struct_name.span());
+
+    let mut variants = Vec::with_capacity(fields.len());
+    let mut names = Vec::with_capacity(fields.len());
+    for field in fields.iter().filter(|f| !f.getter.skip) {
+        let name = match field.field.ident {
+            Some(ref ident) => ident.unraw().to_string(),
+            None => field.getter.base_string(None, field.field.span())?,
+        };
+        let variant = RenameRule::Pascal.apply(&name);
+        let variant = try_ident(&variant, field.field.span()).ok_or_else(|| {
+            Error::new(
+                field.field.span(),
+                format!(
+                    "`field_enum` variant name `{}` derived from base name `{}` is not a valid \
+                     Rust identifier",
+                    variant, name
+                ),
+            )
+        })?;
+        let doc = format!("[`{}::{}`] field", struct_name, field.name_string());
+        variants.push(quote_spanned! { field.field.span() =>
+            #[doc = #doc]
+            #variant
+        });
+        names.push(quote! { #enum_name::#variant => f.write_str(#name) });
+    }
+
+    let doc = format!("Fields of [`{}`] type.", struct_name);
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(::core::marker::Copy, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash, ::core::fmt::Debug)]
+        pub enum #enum_name {
+            #( #variants ),*
+        }
+
+        impl ::core::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match *self {
+                    #( #names ),*
+                }
+            }
+        }
+    })
+}
+
+/// Generates `{Struct}ValidationError` type and `validate` method calling
+/// per-field validators
+fn derive_validate(
+    fields: &[GetterField],
+    struct_name: &Ident,
+) -> Result<(TokenStream2, TokenStream2)> {
+    let error_name = Ident::new(&format!("{}ValidationError", struct_name), struct_name.span());
+    let error_doc = format!("Error returned by [`{}::validate`] method.", struct_name);
+
+    let mut checks = Vec::with_capacity(fields.len());
+    for field in fields {
+        let validator = match field.getter.validator {
+            Some(ref validator) => validator.parse::<ExprPath>()?,
+            None => continue,
+        };
+        let access = field.access();
+        let name = field.name_string();
+        checks.push(quote_spanned! { field.field.span() =>
+            if let ::core::result::Result::Err(message) = #validator(&self.#access) {
+                return ::core::result::Result::Err(#error_name {
+                    field: #name,
+                    message,
+                });
+            }
+        });
+    }
+
+    let error_type = quote! {
+        #[doc = #error_doc]
+        #[derive(::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash, ::core::fmt::Debug)]
+        pub struct #error_name {
+            /// Name of the field which has failed validation
+            pub field: &'static str,
+            /// Message returned by the field validator
+            pub message: ::std::string::String,
+        }
+
+        impl ::core::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, "invalid value of `{}` field: {}", self.field, self.message)
+            }
+        }
+
+        impl ::std::error::Error for #error_name {}
+    };
+
+    let validate_doc = format!(
+        "Validates [`{}`] by running field validators in the order of field declaration.\n\n# \
+         Errors\n\nReturns error for the first field which validator has failed.",
+        struct_name
+    );
+    let validate = quote! {
+        #[doc = #validate_doc]
+        pub fn validate(&self) -> ::core::result::Result<(), #error_name> {
+            #( #checks )*
+            ::core::result::Result::Ok(())
+        }
+    };
+
+    Ok((error_type, validate))
+}
+
+fn derive_enum_impl(
+    data: DataEnum,
+    enum_name: &Ident,
+    global_param: ParametrizedAttr,
+    type_accessor: &HashMap<String, ArgValue>,
+    impl_generics: ImplGenerics,
+    ty_generics: TypeGenerics,
+    where_clause: Option<&WhereClause>,
+) -> Result<TokenStream2> {
+    let other_variants = data.variants.len() > 1;
+    let mut inherited = None;
+    let mut methods = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        methods.extend(derive_variant_methods(
+            variant,
+            enum_name,
+            &global_param,
+            type_accessor,
+            &mut inherited,
+            other_variants,
+        )?)
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #( #methods )*
+        }
+    })
+}
+
+/// Parses field- or variant-level `#[getter]` attribute and combines it with
+/// the type-level one.
+///
+/// Fields without own `#[getter]` and `#[accessor]` attributes share the
+/// type-level arguments, which are validated only once and cached in
+/// `inherited`, so large structs do not re-parse them for each field.
+fn local_getter(
+    attrs: &[Attribute],
+    global_param: &ParametrizedAttr,
+    type_accessor: &HashMap<String, ArgValue>,
+    inherited: &mut Option<GetterDerive>,
+) -> Result<GetterDerive> {
+    if !attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("getter") || attr.path.is_ident("accessor"))
+    {
+        if let Some(getter) = inherited {
+            return Ok(getter.clone());
+        }
+        let mut params = global_param.clone();
+        let none = HashMap::new();
+        if let Some(prefix) = accessor_prefix(&params.args, &none, "", &none, type_accessor)? {
+            params.args.remove("prefix_from_type");
+            params.args.insert("prefix".to_owned(), prefix);
+        }
+        let getter = GetterDerive::try_from(&mut params, AttrLevel::Merged)?;
+        *inherited = Some(getter.clone());
+        return Ok(getter);
+    }
+
+    let mut local_param = ParametrizedAttr::with("getter", attrs)?;
+    let accessor = accessor_args(attrs, false)?;
+    merge_accessor_args(&mut local_param.args, &accessor);
+
+    // First, test individual attribute
+    let _ = GetterDerive::try_from(&mut local_param, AttrLevel::Field)?;
+    let prefix =
+        accessor_prefix(&global_param.args, &local_param.args, "", &accessor, type_accessor)?;
+    // Second, combine global and local together
+    let mut local_args = local_param.args.clone();
+    let mut params = global_param.clone().merged(local_param)?;
+    // Shared prefix follows the type-level one, replacing the prefix derived
+    // from the type like the field-level prefix does
+    if let Some(prefix) = prefix {
+        params.args.remove("prefix_from_type");
+        params.args.insert("prefix".to_owned(), prefix);
+    }
+    // Fields opt in to getters when the type-level attribute skips all of them
+    if local_args.contains_key("expose") {
+        params.args.remove("skip");
+    }
+    // Field-level prefix replaces the one derived from the type at type level
+    if local_args.contains_key("prefix") {
+        params.args.remove("prefix_from_type");
+    }
+    const OVERRIDING: [&str; 4] = ["as_copy", "as_clone", "main", "as_ref"];
+    if local_args.keys().any(|k| OVERRIDING.contains(&k.as_str())) {
+        // we have to use local arguments since they do override globals
+        for arg in OVERRIDING {
+            params.args.remove(arg);
+            local_args
+                .remove(arg)
+                .map(|a| params.args.insert(arg.to_owned(), a));
+        }
+    }
+    GetterDerive::try_from(&mut params, AttrLevel::Merged)
+}
+
+/// Generates `validate_all` method calling all per-field validators and
+/// collecting their errors
+fn derive_validate_all(fields: &[GetterField], struct_name: &Ident) -> Result<TokenStream2> {
+    let mut checks = Vec::with_capacity(fields.len());
+    for field in fields {
+        let validator = match field.getter.validator {
+            Some(ref validator) => validator.parse::<ExprPath>()?,
+            None => continue,
+        };
+        let access = field.access();
+        checks.push(quote_spanned! { field.field.span() =>
+            if let ::core::result::Result::Err(message) = #validator(&self.#access) {
+                errors.push(message);
+            }
+        });
+    }
+
+    let doc = format!(
+        "Validates [`{}`] by running all field validators in the order of field declaration.\n\n# \
+         Errors\n\nReturns messages of all failed validators.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        pub fn validate_all(&self) -> ::core::result::Result<(), ::std::vec::Vec<::std::string::String>> {
+            let mut errors = ::std::vec::Vec::new();
+            #( #checks )*
+            if errors.is_empty() {
+                ::core::result::Result::Ok(())
+            } else {
+                ::core::result::Result::Err(errors)
+            }
+        }
+    })
+}
+
+/// Generates `changed_fields` method comparing all fields which have getters
+fn derive_diff(fields: &[GetterField], struct_name: &Ident) -> Result<TokenStream2> {
+    let mut checks = Vec::with_capacity(fields.len());
+    for field in fields.iter().filter(|f| !f.getter.skip) {
+        let access = field.access();
+        let name = field
+            .getter
+            .base_string(field.field.ident.as_ref(), field.field.span())?;
+        checks.push(quote_spanned! { field.field.span() =>
+            if self.#access != other.#access {
+                changed.push(#name);
+            }
+        });
+    }
+
+    let doc = format!(
+        "Returns base names of getters for [`{}`] fields which values differ between `self` and \
+         `other`, in the order of field declaration.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[must_use]
+        pub fn changed_fields(&self, other: &Self) -> ::std::vec::Vec<&'static str> {
+            let mut changed = ::std::vec::Vec::new();
+            #( #checks )*
+            changed
+        }
+    })
+}
+
+/// Generates `eq_public` method comparing values of the non-skipped fields
+fn derive_eq_public(fields: &[GetterField], struct_name: &Ident) -> TokenStream2 {
+    let checks = fields.iter().filter(|f| !f.getter.skip).map(|field| {
+        let access = field.access();
+        let cfg = field.cfg_attrs();
+        quote_spanned! { field.field.span() =>
+            #cfg
+            if self.#access != other.#access {
+                return false;
+            }
+        }
+    });
+
+    let doc = format!(
+        "Compares [`{}`] values by the fields which have getters, ignoring skipped fields.",
+        struct_name
+    );
+    quote! {
+        #[doc = #doc]
+        #[must_use]
+        pub fn eq_public(&self, other: &Self) -> bool {
+            #( #checks )*
+            true
+        }
+    }
+}
+
+/// Generates `merge` method filling `None` fields with the values from the other
+/// instance. Skipped fields are left intact.
+fn derive_merge(fields: &[GetterField], struct_name: &Ident) -> Result<TokenStream2> {
+    let mut merges = Vec::with_capacity(fields.len());
+    for field in fields.iter().filter(|f| !f.getter.skip) {
+        let ty = &field.field.ty;
+        if !type_last_segment(ty)
+            .map(|segment| segment.ident == "Option")
+            .unwrap_or_default()
+        {
+            return Err(Error::new_spanned(
+                ty,
+                "`merge` getter argument requires all fields with getters to be of `Option<_>` \
+                 type; other fields must be skipped",
+            ));
+        }
+        let access = field.access();
+        let cfg = field.cfg_attrs();
+        merges.push(quote_spanned! { field.field.span() =>
+            #cfg
+            if self.#access.is_none() {
+                self.#access = other.#access;
+            }
+        });
+    }
+    let allow = if merges.is_empty() {
+        quote! { #[allow(unused_variables)] }
+    } else {
+        quote! {}
+    };
+
+    let doc = format!(
+        "Merges other [`{}`] value into this one, taking values of the fields which are `None` \
+         here from `other`. Fields without getters are not changed.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #allow
+        pub fn merge(&mut self, other: Self) {
+            #( #merges )*
+        }
+    })
+}
+
+/// Generates `swap_{field}_{other}` method exchanging values of two fields.
+/// Type mismatch is reported by the compiler at the `swap` argument value.
+fn derive_swap(
+    field: &GetterField,
+    fields: &[GetterField],
+    other: &LitStr,
+    struct_name: &Ident,
+) -> Result<TokenStream2> {
+    let name = field.name_string();
+    let other_field = fields
+        .iter()
+        .find(|f| f.name_string() == other.value() || f.unraw_name() == other.value())
+        .ok_or_else(|| {
+            Error::new(
+                other.span(),
+                format!("`swap` getter argument refers to unknown field `{}`", other.value()),
+            )
+        })?;
+    if other_field.index == field.index {
+        return Err(Error::new(
+            other.span(),
+            format!("`swap` getter argument can't swap field `{}` with itself", name),
+        ));
+    }
+
+    let fn_name = fn_ident(
+        &format!("swap_{}_{}", field.unraw_name(), other_field.unraw_name()),
+        other.span(),
+    )?;
+    let access = field.access();
+    let other_access = other_field.access();
+    let cfg = field.cfg_attrs();
+    let other_cfg = other_field.cfg_attrs();
+    let allow_deprecated =
+        if field.deprecated_attrs().is_empty() && other_field.deprecated_attrs().is_empty() {
+            quote! {}
+        } else {
+            quote! { #[allow(deprecated)] }
+        };
+    let doc = format!(
+        "Swaps values of [`{}::{}`] and [`{}::{}`] fields.",
+        struct_name,
+        name,
+        struct_name,
+        other_field.name_string()
+    );
+    Ok(quote_spanned! { other.span() =>
+        #[doc = #doc]
+        #cfg
+        #other_cfg
+        #allow_deprecated
+        #[inline]
+        pub fn #fn_name(&mut self) {
+            ::core::mem::swap(&mut self.#access, &mut self.#other_access);
+        }
+    })
+}
+
+/// Generates `result` method combining two `Option` fields into `Result`
+fn derive_as_result(
+    fields: &[GetterField],
+    struct_name: &Ident,
+    value: &Ident,
+    error: &Ident,
+) -> Result<TokenStream2> {
+    let inner_ty = |name: &Ident| {
+        let field = fields
+            .iter()
+            .find(|f| f.field.ident.as_ref() == Some(name))
+            .ok_or_else(|| {
+                Error::new(name.span(), format!("struct `{}` has no field `{}`", struct_name, name))
+            })?;
+        type_last_segment(&field.field.ty)
+            .filter(|segment| segment.ident == "Option")
+            .and_then(|segment| generic_type_args(segment).first().copied())
+            .ok_or_else(|| {
+                Error::new_spanned(
+                    &field.field.ty,
+                    "`as_result` getter argument requires both fields to be of `Option` type",
+                )
+            })
+    };
+    let value_ty = inner_ty(value)?;
+    let error_ty = inner_ty(error)?;
+
+    let doc = format!(
+        "Returns [`{0}::{1}`] as `Ok` if it is set, or [`{0}::{2}`] as `Err` otherwise.\n\n# \
+         Panics\n\nIf neither of the fields is set.",
+        struct_name, value, error
+    );
+    let msg = format!("neither `{0}::{1}` nor `{0}::{2}` is set", struct_name, value, error);
+    Ok(quote! {
+        #[doc = #doc]
+        pub fn result(&self) -> ::core::result::Result<&#value_ty, &#error_ty> {
+            match (&self.#value, &self.#error) {
+                (::core::option::Option::Some(value), _) => ::core::result::Result::Ok(value),
+                (::core::option::Option::None, ::core::option::Option::Some(error)) => ::core::result::Result::Err(error),
+                (::core::option::Option::None, ::core::option::Option::None) => ::core::panic!(#msg),
+            }
+        }
+    })
+}
+
+/// Generates method borrowing the listed fields together as a tuple
+fn derive_group(
+    fields: &[GetterField],
+    struct_name: &Ident,
+    fn_name: &Ident,
+    members: &[Ident],
+) -> Result<TokenStream2> {
+    let mut tys = Vec::with_capacity(members.len());
+    for name in members {
+        let field = fields
+            .iter()
+            .find(|f| f.field.ident.as_ref() == Some(name))
+            .ok_or_else(|| {
+                Error::new(name.span(), format!("struct `{}` has no field `{}`", struct_name, name))
+            })?;
+        tys.push(&field.field.ty);
+    }
+
+    let links = members
+        .iter()
+        .map(|name| format!("[`{}::{}`]", struct_name, name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let doc = format!("Returns references to {} fields.", links);
+    Ok(quote! {
+        #[doc = #doc]
+        pub fn #fn_name(&self) -> ( #( &#tys, )* ) {
+            ( #( &self.#members, )* )
+        }
+    })
+}
+
+/// Generates test module checking that the getters of a `Default`-constructed
+/// struct return values of the fields
+fn derive_tests(
+    fields: &[GetterField],
+    struct_name: &Ident,
+    trait_name: Option<&Ident>,
+) -> Result<TokenStream2> {
+    let mut tests = Vec::with_capacity(fields.len());
+    for getter_field in fields {
+        let GetterField {
+            field, ref getter, ..
+        } = *getter_field;
+        // Getters with custom expressions don't return the field value, while
+        // reference and boxed trait object fields are not borrowed as
+        // references to the field
+        if getter.skip ||
+            getter.expr.is_some() ||
+            matches!(field.ty, Type::Reference(_)) ||
+            boxed_trait_object(&field.ty).is_some()
+        {
+            continue;
+        }
+        let access = getter_field.access();
+        let cfg = getter_field.cfg_attrs();
+        let is_option = getter.option &&
+            type_last_segment(&field.ty)
+                .map(|segment| segment.ident == "Option")
+                .unwrap_or_default();
+        for method in getter.all_methods() {
+            // Pinned projections and getters with custom receivers can't be
+            // called on a plain instance, and converted values can't be
+            // compared with the field value
+            let custom_receiver = getter.self_ty.is_some() &&
+                matches!(
+                    method,
+                    GetterMethod::AsRef |
+                        GetterMethod::AsDeref |
+                        GetterMethod::AsStr |
+                        GetterMethod::AsSlice
+                );
+            if matches!(method, GetterMethod::Pin | GetterMethod::AsInto) || custom_receiver {
+                continue;
+            }
+            let fn_name = getter.getter_fn_ident(method, field.ident.as_ref(), field.span())?;
+            let target = &getter.borrow_as;
+            let expected = match (method, is_option) {
+                (GetterMethod::AsRef, true) if target.is_some() => quote! {
+                    expected.#access.as_ref().map(::core::convert::AsRef::<#target>::as_ref)
+                },
+                (GetterMethod::AsRef, false) if target.is_some() => quote! {
+                    ::core::convert::AsRef::<#target>::as_ref(&expected.#access)
+                },
+                (GetterMethod::AsRef, true) => quote! { expected.#access.as_ref() },
+                (GetterMethod::AsMut, true) => quote! { expected.#access.as_mut() },
+                _ => method.ret_value(quote! { expected.#access }),
+            };
+            let mutability = match method {
+                GetterMethod::AsMut => quote! { mut },
+                _ => quote! {},
+            };
+            tests.push(quote_spanned! { field.span() =>
+                #cfg
+                #[test]
+                fn #fn_name() {
+                    let #mutability instance = <super::#struct_name as ::core::default::Default>::default();
+                    let #mutability expected = <super::#struct_name as ::core::default::Default>::default();
+                    ::core::assert!(instance.#fn_name() == #expected);
+                }
+            });
+        }
+    }
+
+    let mod_name = Ident::new(
+        &format!("{}_getter_tests", RenameRule::Snake.apply(&struct_name.to_string())),
+        struct_name.span(),
+    );
+    // Getters placed into a trait are called through the trait
+    let trait_use = trait_name.map(|trait_name| {
+        quote! {
+            #[allow(unused_imports)]
+            use super::#trait_name;
+        }
+    });
+    Ok(quote! {
+        #[cfg(test)]
+        mod #mod_name {
+            #trait_use
+            #( #tests )*
+        }
+    })
+}
+
+/// Generates `from_unstructured` constructor reading values of all fields
+/// with the `arbitrary` crate
+fn derive_arbitrary_ctor(fields: &Fields, struct_name: &Ident) -> TokenStream2 {
+    let bounds = fields.iter().map(|field| {
+        let ty = &field.ty;
+        quote_spanned! { field.span() => #ty: ::arbitrary::Arbitrary<'unstructured> }
+    });
+    let values = fields.iter().map(|field| {
+        let name = field.ident.as_ref().map(|ident| quote! { #ident: });
+        quote_spanned! { field.span() => #name ::arbitrary::Arbitrary::arbitrary(u)? }
+    });
+    let value = match fields {
+        Fields::Named(_) => quote! { Self { #( #values ),* } },
+        _ => quote! { Self(#( #values ),*) },
+    };
+
+    let doc = format!(
+        "Constructs [`{}`] reading values of all its fields from the provided unstructured data.",
+        struct_name
+    );
+    quote! {
+        #[doc = #doc]
+        pub fn from_unstructured<'unstructured>(
+            u: &mut ::arbitrary::Unstructured<'unstructured>,
+        ) -> ::arbitrary::Result<Self>
+        where
+            #( #bounds ),*
+        {
+            ::core::result::Result::Ok(#value)
+        }
+    }
+}
+
+/// Generates `zeroize` method clearing fields marked with `zeroize` (or all
+/// fields, including skipped ones, with `zeroize_all`) with `Zeroize` trait.
+/// Fields gated on a feature of the crate using the derive are assigned default
+/// values when the feature is disabled.
+fn derive_zeroize(fields: &[GetterField], struct_name: &Ident) -> TokenStream2 {
+    let clears = fields
+        .iter()
+        .filter_map(|f| {
+            f.getter
+                .zeroize
+                .as_ref()
+                .or(f.getter.zeroize_all.as_ref())
+                .map(|gate| (f, gate))
+        })
+        .map(|(field, gate)| {
+            let access = field.access();
+            let cfg = field.cfg_attrs();
+            let feature_cfg = gate.cfg_attr();
+            let fallback = gate.cfg_not_attr().map(|cfg_not| {
+                quote! {
+                    #cfg_not
+                    let _ = ::core::mem::take(&mut self.#access);
+                }
+            });
+            quote_spanned! { field.field.span() =>
+                #cfg
+                {
+                    #feature_cfg
+                    ::zeroize::Zeroize::zeroize(&mut self.#access);
+                    #fallback
+                }
+            }
+        });
+
+    let doc = format!(
+        "Clears sensitive fields of [`{}`], overwriting them with zeros (or with their default \
+         values, if the feature they are gated on is disabled).",
+        struct_name
+    );
+    quote! {
+        #[doc = #doc]
+        pub fn zeroize(&mut self) {
+            #( #clears )*
+        }
+    }
+}
+
+/// Selects non-skipped fields, which are the elements of the tuple used by
+/// `into_parts` and `from_parts` methods
+fn parts_fields<'a, 'b>(
+    fields: &'b [GetterField<'a>],
+    arg: &str,
+) -> Result<Vec<&'b GetterField<'a>>> {
+    let fields = fields.iter().filter(|f| !f.getter.skip).collect::<Vec<_>>();
+    // Tuple elements can't be conditionally compiled
+    if let Some(field) = fields
+        .iter()
+        .find(|f| f.field.attrs.iter().any(|attr| attr.path.is_ident("cfg")))
+    {
+        return Err(Error::new(
+            field.field.span(),
+            format!(
+                "`{}` getter argument can't be used with conditionally compiled fields unless \
+                 they are skipped",
+                arg
+            ),
+        ));
+    }
+    Ok(fields)
+}
+
+/// Generates `into_parts` method moving values of the non-skipped fields into
+/// a tuple
+fn derive_into_parts(fields: &[GetterField], struct_name: &Ident) -> Result<TokenStream2> {
+    let fields = parts_fields(fields, "into_parts")?;
+    let allow = if fields.is_empty() {
+        quote! { #[allow(clippy::unused_unit)] }
+    } else {
+        quote! {}
+    };
+    let types = fields.iter().map(|f| &f.field.ty);
+    let values = fields.iter().map(|f| {
+        let access = f.access();
+        quote_spanned! { f.field.span() => self.#access }
+    });
+
+    let doc = format!(
+        "Consumes [`{}`] returning tuple with the values of all fields which have getters, in the \
+         order of field declaration.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[must_use]
+        #allow
+        pub fn into_parts(self) -> (#( #types, )*) {
+            (#( #values, )*)
+        }
+    })
+}
+
+/// Generates `try_into_inner` method consuming the struct and returning value
+/// of its single non-skipped field after validating the struct, or
+/// `into_inner` method if the struct is not validated
+fn derive_try_into_inner(
+    fields: &[GetterField],
+    struct_name: &Ident,
+    validate: bool,
+) -> Result<TokenStream2> {
+    let mut inner = fields.iter().filter(|f| !f.getter.skip);
+    let field = match (inner.next(), inner.next()) {
+        (Some(field), None) => field,
+        (_, other) => {
+            return Err(Error::new(
+                other
+                    .map(|f| f.field.span())
+                    .unwrap_or_else(Span::call_site),
+                "`try_into_inner` getter argument requires struct to have exactly one field with \
+                 getters; other fields must be skipped",
+            ));
+        }
+    };
+    let ty = &field.field.ty;
+    let access = field.access();
+
+    Ok(if validate {
+        let error_name = Ident::new(&format!("{}ValidationError", struct_name), struct_name.span());
+        let doc = format!(
+            "Validates [`{}`] and consumes it returning value of [`{}::{}`] field.\n\n# \
+             Errors\n\nReturns error for the first field which validator has failed.",
+            struct_name,
+            struct_name,
+            field.name_string()
+        );
+        quote_spanned! { field.field.span() =>
+            #[doc = #doc]
+            pub fn try_into_inner(self) -> ::core::result::Result<#ty, #error_name> {
+                self.validate()?;
+                ::core::result::Result::Ok(self.#access)
+            }
+        }
+    } else {
+        let doc = format!(
+            "Consumes [`{}`] returning value of [`{}::{}`] field.",
+            struct_name,
+            struct_name,
+            field.name_string()
+        );
+        quote_spanned! { field.field.span() =>
+            #[doc = #doc]
+            #[must_use]
+            pub fn into_inner(self) -> #ty {
+                self.#access
+            }
+        }
+    })
+}
+
+/// Generates `from_parts` constructor taking tuple with the values of the
+/// non-skipped fields in the same order as `into_parts` returns them; skipped
+/// fields are initialized with their default values
+fn derive_from_parts(fields: &[GetterField], struct_name: &Ident) -> Result<TokenStream2> {
+    let parts = parts_fields(fields, "from_parts")?;
+    let allow = if parts.is_empty() {
+        quote! { #[allow(unused_variables)] }
+    } else {
+        quote! {}
+    };
+    let types = parts.iter().map(|f| &f.field.ty);
+    let mut element = 0;
+    let mut values = Vec::with_capacity(fields.len());
+    for field in fields {
+        let access = field.access();
+        let value = if field.getter.skip {
+            let cfg = field.cfg_attrs();
+            quote_spanned! { field.field.span() =>
+                #cfg #access: ::core::default::Default::default()
+            }
+        } else {
+            let index = Index::from(element);
+            element += 1;
+            quote_spanned! { field.field.span() => #access: parts.#index }
+        };
+        values.push(value);
+    }
+
+    let doc = format!(
+        "Constructs [`{}`] from tuple with the values of all fields which have getters, in the \
+         order of field declaration. Fields without getters are set to their default values.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #allow
+        pub fn from_parts(parts: (#( #types, )*)) -> Self {
+            Self { #( #values ),* }
+        }
+    })
+}
+
+/// Generates `const` constructor taking values for all struct fields
+fn derive_const_new(
+    fields: &[GetterField],
+    kind: &Fields,
+    struct_name: &Ident,
+) -> Result<TokenStream2> {
+    let mut args = Vec::with_capacity(fields.len());
+    let mut values = Vec::with_capacity(fields.len());
+    for field in fields {
+        let span = field.field.span();
+        let ty = &field.field.ty;
+        let cfg = field.cfg_attrs();
+        let name = match (&field.field.ident, &field.getter.base) {
+            (Some(ident), _) => ident.clone(),
+            (None, Some(base)) => fn_ident(&base.value(), base.span())?,
+            (None, None) => Ident::new(&format!("field_{}", field.index), span),
+        };
+        args.push(quote_spanned! { span => #cfg #name: #ty });
+        values.push(quote_spanned! { span => #cfg #name });
+    }
+    let value = match kind {
+        Fields::Named(_) => quote! { Self { #( #values ),* } },
+        _ => quote! { Self(#( #values ),*) },
+    };
+
+    let doc = format!(
+        "Constructs [`{}`] from the values of all its fields, including the ones which do not \
+         have getters.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        pub const fn new(#( #args ),*) -> Self {
+            #value
+        }
+    })
+}
+
+/// Generates `with_capacity` constructor preallocating `Vec`, `VecDeque`,
+/// `String`, `HashMap` and `HashSet` fields; other fields get their default
+/// values
+fn derive_with_capacity(fields: &Fields, struct_name: &Ident) -> TokenStream2 {
+    let values = fields.iter().map(|field| {
+        let name = field.ident.as_ref().map(|ident| quote! { #ident: });
+        let ty = &field.ty;
+        let cfg = field.attrs.iter().filter(|attr| attr.path.is_ident("cfg"));
+        let value = match type_last_segment(ty) {
+            Some(segment)
+                if ["Vec", "VecDeque", "String"]
+                    .iter()
+                    .any(|name| segment.ident == name) =>
+            {
+                quote_spanned! { field.span() => <#ty>::with_capacity(capacity) }
+            }
+            Some(segment) if segment.ident == "HashMap" || segment.ident == "HashSet" => {
+                quote_spanned! { field.span() =>
+                    <#ty>::with_capacity_and_hasher(capacity, ::core::default::Default::default())
+                }
+            }
+            _ => quote_spanned! { field.span() => ::core::default::Default::default() },
+        };
+        quote! { #( #cfg )* #name #value }
+    });
+    let value = match fields {
+        Fields::Named(_) => quote! { Self { #( #values ),* } },
+        _ => quote! { Self(#( #values ),*) },
+    };
+
+    let doc = format!(
+        "Constructs [`{}`] with the collection fields (`Vec`, `VecDeque`, `String`, `HashMap` and \
+         `HashSet`) preallocated to hold at least `capacity` elements, and other fields \
+         initialized with their default values.",
+        struct_name
+    );
+    quote! {
+        #[doc = #doc]
+        pub fn with_capacity(capacity: usize) -> Self {
+            #value
+        }
+    }
+}
+
+/// Generates `Frozen{Struct}` type, in which `Vec` fields are replaced with
+/// boxed slices, and `freeze` method converting the struct into it
+fn derive_freeze(
+    fields: &Fields,
+    struct_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+) -> (TokenStream2, TokenStream2) {
+    let frozen_name = Ident::new(&format!("Frozen{}", struct_name), struct_name.span());
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut decls = Vec::with_capacity(fields.len());
+    let mut values = Vec::with_capacity(fields.len());
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("cfg") || attr.path.is_ident("doc"));
+        let cfg = field.attrs.iter().filter(|attr| attr.path.is_ident("cfg"));
+        let name = field.ident.as_ref().map(|ident| quote! { #ident: });
+        let access = match field.ident {
+            Some(ref ident) => quote! { #ident },
+            None => {
+                let index = Index::from(index);
+                quote! { #index }
+            }
+        };
+        let vec_item = type_last_segment(&field.ty)
+            .filter(|segment| segment.ident == "Vec")
+            .and_then(|segment| match generic_type_args(segment)[..] {
+                [item] => Some(item),
+                _ => None,
+            });
+        let (ty, value) = match vec_item {
+            Some(item) => (
+                quote_spanned! { field.span() => ::std::boxed::Box<[#item]> },
+                quote_spanned! { field.span() => self.#access.into_boxed_slice() },
+            ),
+            None => {
+                let ty = &field.ty;
+                (quote! { #ty }, quote_spanned! { field.span() => self.#access })
+            }
+        };
+        decls.push(quote! { #( #attrs )* #vis #name #ty });
+        values.push(quote! { #( #cfg )* #name #value });
+    }
+
+    let frozen_doc = format!(
+        "Immutable form of [`{}`] returned by [`{}::freeze`] method, in which `Vec` fields are \
+         replaced with boxed slices.",
+        struct_name, struct_name
+    );
+    let frozen = match fields {
+        Fields::Named(_) => quote! {
+            #[doc = #frozen_doc]
+            #vis struct #frozen_name #generics #where_clause {
+                #( #decls ),*
+            }
+        },
+        _ => quote! {
+            #[doc = #frozen_doc]
+            #vis struct #frozen_name #generics (#( #decls ),*) #where_clause;
+        },
+    };
+    let value = match fields {
+        Fields::Named(_) => quote! { #frozen_name { #( #values ),* } },
+        _ => quote! { #frozen_name(#( #values ),*) },
+    };
+
+    let doc = format!(
+        "Consumes [`{}`] converting it into [`{}`], which has `Vec` fields converted into boxed \
+         slices and other fields moved unchanged.",
+        struct_name, frozen_name
+    );
+    let freeze = quote! {
+        #[doc = #doc]
+        #[must_use]
+        pub fn freeze(self) -> #frozen_name #ty_generics {
+            #value
+        }
+    };
+    (frozen, freeze)
+}
+
+/// Generates `strategy` method composing `proptest` strategies for the values
+/// of all fields. The strategies are nested in pairs, since tuple strategies
+/// are limited in size.
+fn derive_proptest_strategy(fields: &Fields, struct_name: &Ident) -> TokenStream2 {
+    let names = (0..fields.len())
+        .map(|index| Ident::new(&format!("field_{}", index), Span::call_site()))
+        .collect::<Vec<_>>();
+    let (strategies, pattern) = fields.iter().zip(&names).rev().fold(
+        (quote! { ::proptest::strategy::Just(()) }, quote! { () }),
+        |(strategies, pattern), (field, name)| {
+            let ty = &field.ty;
+            (
+                quote_spanned! { field.span() =>
+                    (::proptest::arbitrary::any::<#ty>(), #strategies)
+                },
+                quote! { (#name, #pattern) },
+            )
+        },
+    );
+    let bounds = fields.iter().map(|field| {
+        let ty = &field.ty;
+        quote_spanned! { field.span() => #ty: ::proptest::arbitrary::Arbitrary }
+    });
+    let values = fields
+        .iter()
+        .zip(&names)
+        .map(|(field, name)| match field.ident {
+            Some(ref ident) => quote! { #ident: #name },
+            None => quote! { #name },
+        });
+    let value = match fields {
+        Fields::Named(_) => quote! { Self { #( #values ),* } },
+        _ => quote! { Self(#( #values ),*) },
+    };
+
+    let doc = format!(
+        "Returns `proptest` strategy generating [`{}`] values out of arbitrary values of all its \
+         fields.",
+        struct_name
+    );
+    quote! {
+        #[doc = #doc]
+        pub fn strategy() -> impl ::proptest::strategy::Strategy<Value = Self>
+        where
+            Self: ::core::fmt::Debug,
+            #( #bounds ),*
+        {
+            ::proptest::strategy::Strategy::prop_map(#strategies, |#pattern| #value)
+        }
+    }
+}
+
+/// Generates `FIELDS` constant listing getter base names of the non-skipped
+/// fields
+fn derive_field_names(fields: &[GetterField], struct_name: &Ident) -> Result<TokenStream2> {
+    let mut names = Vec::with_capacity(fields.len());
+    for field in fields.iter().filter(|f| !f.getter.skip) {
+        let cfg = field.cfg_attrs();
+        let name = field
+            .getter
+            .base_string(field.field.ident.as_ref(), field.field.span())?;
+        names.push(quote_spanned! { field.field.span() => #cfg #name });
+    }
+
+    let doc = format!(
+        "Getter base names of [`{}`] fields, in the order of field declaration.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        pub const FIELDS: &'static [&'static str] = &[#( #names ),*];
     })
 }
 
-fn derive_field_methods(
-    field: &Field,
-    index: usize,
-    struct_name: &Ident,
-    global_param: &ParametrizedAttr,
-) -> Result<Vec<TokenStream2>> {
-    let mut local_param = ParametrizedAttr::with("getter", &field.attrs)?;
+/// Generates `snapshot` method listing debug representations of the field
+/// values
+fn derive_snapshot(fields: &[GetterField], struct_name: &Ident) -> Result<TokenStream2> {
+    let mut entries = Vec::with_capacity(fields.len());
+    for field in fields.iter().filter(|f| !f.getter.skip) {
+        let access = field.access();
+        let cfg = field.cfg_attrs();
+        let name = field
+            .getter
+            .base_string(field.field.ident.as_ref(), field.field.span())?;
+        entries.push(quote_spanned! { field.field.span() =>
+            #cfg
+            snapshot.push((::std::string::String::from(#name), ::std::format!("{:?}", self.#access)));
+        });
+    }
 
-    // First, test individual attribute
-    let _ = GetterDerive::try_from(&mut local_param, false)?;
-    // Second, combine global and local together
-    let mut local_args = local_param.args.clone();
-    let mut params = global_param.clone().merged(local_param)?;
-    if local_args
-        .keys()
-        .any(|k| k == "as_copy" || k == "as_clone" || k == "as_ref")
-    {
-        // we have to use local arguments since they do override globals
-        params.args.remove("as_copy");
-        params.args.remove("as_clone");
-        params.args.remove("as_ref");
-        local_args
-            .remove("as_copy")
-            .map(|a| params.args.insert("as_copy".to_owned(), a));
-        local_args
-            .remove("as_clone")
-            .map(|a| params.args.insert("as_clone".to_owned(), a));
-        local_args
-            .remove("as_ref")
-            .map(|a| params.args.insert("as_ref".to_owned(), a));
-    }
-    let getter = GetterDerive::try_from(&mut params, false)?;
+    let doc = format!(
+        "Returns pairs of getter base names and debug representations of the values for [`{}`] \
+         fields, in the order of field declaration.",
+        struct_name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[must_use]
+        pub fn snapshot(&self) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+            let mut snapshot = ::std::vec::Vec::new();
+            #( #entries )*
+            snapshot
+        }
+    })
+}
 
-    if getter.skip {
+/// Generates `checksum` method folding FNV-1a hash over the byte contents of
+/// the fields marked with `checksum_include`
+fn derive_checksum(fields: &[GetterField], struct_name: &Ident) -> TokenStream2 {
+    let contents = fields
+        .iter()
+        .filter(|f| f.getter.checksum_include)
+        .map(|field| {
+            let access = field.access();
+            quote_spanned! { field.field.span() =>
+                ::core::convert::AsRef::<[u8]>::as_ref(&self.#access)
+            }
+        });
+
+    let doc = format!(
+        "Computes checksum of [`{}`] fields marked with `#[getter(checksum_include)]`.\n\nThe \
+         checksum is a 64-bit FNV-1a hash over the byte contents of the fields, in the order of \
+         their declaration, each of them prefixed with its length encoded as a little-endian \
+         `u64` value.",
+        struct_name
+    );
+    quote! {
+        #[doc = #doc]
+        #[must_use]
+        pub fn checksum(&self) -> u64 {
+            const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+            const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+            let mut hash = FNV_OFFSET_BASIS;
+            for bytes in &[#( #contents ),*] {
+                let len = (bytes.len() as u64).to_le_bytes();
+                for byte in len.iter().chain(bytes.iter()) {
+                    hash ^= u64::from(*byte);
+                    hash = hash.wrapping_mul(FNV_PRIME);
+                }
+            }
+            hash
+        }
+    }
+}
+
+/// Derived getter method split into the signature and the body, such that it
+/// can be placed either into an inherent impl block or into a trait
+struct GetterFn {
+    span: Span,
+    name: Ident,
+    /// Getter argument the method is derived for, used in error messages
+    arg: &'static str,
+    vis: TokenStream2,
+    /// Conditional compilation attributes of the field
+    cfg: TokenStream2,
+    /// Attributes of the method declaration, like docs
+    attrs: TokenStream2,
+    /// Attributes of the method implementation, like `#[inline]`
+    impl_attrs: TokenStream2,
+    constness: TokenStream2,
+    /// Method signature without visibility and constness
+    sig: TokenStream2,
+    body: TokenStream2,
+}
+
+impl GetterFn {
+    fn inherent(&self) -> TokenStream2 {
+        let GetterFn {
+            vis,
+            cfg,
+            attrs,
+            impl_attrs,
+            constness,
+            sig,
+            body,
+            ..
+        } = self;
+        quote_spanned! { self.span =>
+            #cfg
+            #attrs
+            #impl_attrs
+            #vis #constness #sig {
+                #body
+            }
+        }
+    }
+
+    fn trait_item(&self) -> TokenStream2 {
+        let GetterFn {
+            cfg, attrs, sig, ..
+        } = self;
+        quote_spanned! { self.span =>
+            #cfg
+            #attrs
+            #sig;
+        }
+    }
+
+    fn trait_impl_item(&self) -> TokenStream2 {
+        let GetterFn {
+            cfg,
+            impl_attrs,
+            sig,
+            body,
+            ..
+        } = self;
+        quote_spanned! { self.span =>
+            #cfg
+            #impl_attrs
+            #sig {
+                #body
+            }
+        }
+    }
+}
+
+fn derive_field_methods(getter_field: &GetterField, struct_name: &Ident) -> Result<Vec<GetterFn>> {
+    let GetterField {
+        index,
+        field,
+        ref getter,
+    } = *getter_field;
+
+    // With `no_getters` only the type-level companion items are derived
+    if getter.skip || getter.no_getters {
         return Ok(Vec::new());
     }
 
+    let coverage = getter.coverage_attr();
+    let inline = &getter.inline;
+    let cfg = getter_field.cfg_attrs();
+    let deprecated = getter_field.deprecated_attrs();
+    let forwarded = getter_field.forwarded_attrs();
+    let field_label = getter_field.name_string();
+    // Methods of deprecated fields are deprecated themselves, but this doesn't
+    // prevent the lint from firing on the field access in their bodies
+    let allow_deprecated = if deprecated.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[allow(deprecated)] }
+    };
+    let allow = &getter.allow;
+    let impl_attrs = quote! { #inline #coverage #allow #allow_deprecated };
     let field_name = field.ident.as_ref();
+    let field_access = getter_field.access();
     let ty = &field.ty;
-    let doc = field.attrs.iter().find(|a| a.path.is_ident("doc"));
+    let doc = field
+        .attrs
+        .iter()
+        .filter(|a| a.path.is_ident("doc"))
+        .collect::<Vec<_>>();
+
+    if getter.as_str.is_some() && !is_str_like(ty) {
+        return Err(Error::new_spanned(
+            ty,
+            "`as_str` getter argument requires field to be of `String`, `Box<str>`, `Rc<str>` or \
+             `Arc<str>` type",
+        ));
+    }
+    if getter.as_slice.is_some() && slice_elem(getter.ret.as_ref().unwrap_or(ty)).is_none() {
+        return Err(Error::new_spanned(
+            ty,
+            "`as_slice` getter argument requires field to be of `Vec<_>` or array type",
+        ));
+    }
+
+    if getter.cached.is_some() && !matches!(getter.all_methods().as_slice(), [GetterMethod::AsRef])
+    {
+        return Err(Error::new(
+            field.span(),
+            "`cached` getter argument returns reference to the cached value and requires `as_ref` \
+             to be the only method derived for the field",
+        ));
+    }
+
+    if getter.must_use && matches!(getter.all_methods().as_slice(), [GetterMethod::AsMut]) {
+        return Err(Error::new(
+            field.span(),
+            "`must_use` getter argument applies only to the methods returning values (`as_copy`, \
+             `as_clone` and `main`) and is meaningless for `as_mut`",
+        ));
+    }
+
+    // With `option` argument `Option<T>` fields are borrowed as `Option<&T>`
+    let option_inner = type_last_segment(ty)
+        .filter(|segment| getter.option && segment.ident == "Option")
+        .and_then(|segment| generic_type_args(segment).first().copied());
+
+    // Fields which are references are borrowed by copying (or reborrowing) the
+    // reference instead of returning reference to it
+    let reference = match ty {
+        Type::Reference(reference) => Some(reference),
+        _ => None,
+    };
+
+    // Boxed trait objects are borrowed as trait object references
+    let boxed_dyn = boxed_trait_object(ty);
+
+    // Type exposed by the getters, which may differ from the field type if
+    // `ret` argument is given
+    let ret_ty = getter.ret.as_ref().unwrap_or(ty);
 
     let mut res = Vec::with_capacity(3);
     for method in getter.all_methods() {
         let fn_name = getter.getter_fn_ident(method, field_name, field.span())?;
-        let fn_doc = getter.getter_fn_doc(method, struct_name, field_name, index, doc);
-        let ret_prefix = method.ret_prefix();
-        let ret_suffix = method.ret_suffix();
-        let mut_prefix = method.mut_prefix();
+        let fn_doc = getter.getter_fn_doc(method, struct_name, field_name, index, &doc);
+        let lifetime = getter.lifetime(method);
+        let (ret_type, ret_value) = match (method, option_inner, reference) {
+            // Pinning projection is sound only if the field is never moved
+            // out of the pinned struct, which is documented as a requirement
+            // of the `pin` argument
+            (GetterMethod::Pin, ..) => (getter.ret_type(method, ty), quote! {
+                unsafe { ::core::pin::Pin::map_unchecked(self, |s| &s.#field_access) }
+            }),
+            _ if getter.cached.is_some() => {
+                let (cached, expr) = (&getter.cached, &getter.expr);
+                (getter.ret_type(method, ret_ty), quote! { #cached.get_or_init(|| #expr) })
+            }
+            _ if getter.expr.is_some() => {
+                let expr = &getter.expr;
+                (getter.ret_type(method, ret_ty), quote! { #expr })
+            }
+            (GetterMethod::AsInto, ..) => {
+                (getter.ret_type(method, ty), method.ret_value(quote! { self.#field_access }))
+            }
+            (GetterMethod::ToOwned, _, Some(_)) => (getter.ret_type(method, ty), quote! {
+                ::std::borrow::ToOwned::to_owned(&*self.#field_access)
+            }),
+            (GetterMethod::AsRef, Some(_), _) if getter.borrow_as.is_some() => {
+                let target = &getter.borrow_as;
+                (quote! { ::core::option::Option<&#lifetime #target> }, quote! {
+                    self.#field_access.as_ref().map(::core::convert::AsRef::<#target>::as_ref)
+                })
+            }
+            (GetterMethod::AsRef, None, _) if getter.borrow_as.is_some() => {
+                let target = &getter.borrow_as;
+                (
+                    getter.ret_type(method, ty),
+                    quote! { ::core::convert::AsRef::<#target>::as_ref(&self.#field_access) },
+                )
+            }
+            (GetterMethod::AsRef, Some(inner), _) => (
+                quote! { ::core::option::Option<&#lifetime #inner> },
+                quote! { self.#field_access.as_ref() },
+            ),
+            (
+                GetterMethod::AsRef,
+                None,
+                Some(TypeReference {
+                    mutability: None, ..
+                }),
+            ) => (quote! { #ty }, quote! { self.#field_access }),
+            (GetterMethod::AsRef, None, Some(TypeReference { elem, .. })) => {
+                (quote! { &#lifetime #elem }, quote! { &*self.#field_access })
+            }
+            (GetterMethod::AsRef, None, None) if boxed_dyn.is_some() => {
+                (quote! { &#lifetime #boxed_dyn }, quote! { &*self.#field_access })
+            }
+            (GetterMethod::AsMut, Some(inner), _) => (
+                quote! { ::core::option::Option<&#lifetime mut #inner> },
+                quote! { self.#field_access.as_mut() },
+            ),
+            _ => (getter.ret_type(method, ty), method.ret_value(quote! { self.#field_access })),
+        };
+        // Borrowing `Option` inner value as a target type requires bound on the
+        // inner value type
+        let where_bound = match (method, option_inner) {
+            (GetterMethod::AsRef, Some(inner)) => getter.where_bound(method, inner),
+            _ => getter.where_bound(method, ret_ty),
+        };
+        let receiver = getter.receiver(method);
+        let fn_generics = getter.fn_generics(method);
+        let constness = getter.constness(method);
+        // Values of `Result` and `Option` must not be silently discarded when
+        // they are returned by value
+        let must_use = match method {
+            GetterMethod::Main { .. } if getter.must_use || is_must_use_type(ret_ty) => {
+                quote! { #[must_use] }
+            }
+            GetterMethod::Into | GetterMethod::AsInto | GetterMethod::ToOwned => {
+                quote! { #[must_use] }
+            }
+            _ => quote! {},
+        };
+
+        let sig = quote_spanned! { field.span() =>
+            fn #fn_name #fn_generics (#receiver) -> #ret_type #where_bound
+        };
+        res.push(GetterFn {
+            span: field.span(),
+            name: fn_name.clone(),
+            arg: getter.method_arg(method),
+            vis: getter.vis(method),
+            cfg: cfg.clone(),
+            attrs: quote! { #fn_doc #deprecated #must_use #forwarded },
+            impl_attrs: impl_attrs.clone(),
+            constness: constness.clone(),
+            sig,
+            body: getter.guarded_body(struct_name, &field_label, &fn_name, ret_value.clone()),
+        });
+
+        if getter.keep_old {
+            let mut old_getter = getter.clone();
+            old_getter.rename = None;
+            let old_name = old_getter.getter_fn_ident(method, field_name, field.span())?;
+            let old_doc = getter
+                .synthesized_doc(format!("Old name of [`{}::{}`] method.", struct_name, fn_name));
+            let note = format!("renamed to `{}`", fn_name);
+            res.push(GetterFn {
+                span: field.span(),
+                name: old_name.clone(),
+                arg: "keep_old",
+                vis: getter.vis(method),
+                cfg: cfg.clone(),
+                attrs: quote! {
+                    #old_doc
+                    #[deprecated(note = #note)]
+                    #[doc(hidden)]
+                    #must_use
+                },
+                impl_attrs: impl_attrs.clone(),
+                constness: constness.clone(),
+                sig: quote_spanned! { field.span() =>
+                    fn #old_name #fn_generics (#receiver) -> #ret_type #where_bound
+                },
+                body: quote! { self.#fn_name() },
+            });
+        }
+
+        if let Some(ref alias) = getter.alias {
+            let mut alias_getter = getter.clone();
+            alias_getter.base = Some(alias.clone());
+            let alias_name = alias_getter.getter_fn_ident(method, field_name, alias.span())?;
+            let alias_doc = getter
+                .synthesized_doc(format!("Alias for [`{}::{}`] method.", struct_name, fn_name));
+            // Deprecation of the alias itself replaces the one of the field
+            let deprecation = if getter.deprecated_alias {
+                let note = format!("use `{}` instead", fn_name);
+                quote! {
+                    #[deprecated(note = #note)]
+                    #[doc(hidden)]
+                }
+            } else {
+                deprecated.clone()
+            };
+            res.push(GetterFn {
+                span: field.span(),
+                name: alias_name.clone(),
+                arg: "alias",
+                vis: getter.vis(method),
+                cfg: cfg.clone(),
+                attrs: quote! { #alias_doc #deprecation #must_use },
+                impl_attrs: impl_attrs.clone(),
+                constness,
+                sig: quote_spanned! { field.span() =>
+                    fn #alias_name #fn_generics (#receiver) -> #ret_type #where_bound
+                },
+                body: getter.guarded_body(struct_name, &field_label, &alias_name, ret_value),
+            });
+        }
+    }
+
+    if let Some(ref suffix) = getter.is_empty {
+        let is_option_vec = type_last_segment(ty)
+            .filter(|segment| segment.ident == "Option")
+            .and_then(|segment| generic_type_args(segment).first().copied())
+            .and_then(type_last_segment)
+            .map(|segment| segment.ident == "Vec")
+            .unwrap_or_default();
+        if !is_option_vec {
+            return Err(Error::new_spanned(
+                ty,
+                "`is_empty` getter argument requires field to be of `Option<Vec<_>>` type",
+            ));
+        }
+        let fn_name = getter.compose_fn_ident(&suffix.value(), field_name, field.span())?;
+        let fn_doc = getter.synthesized_doc(format!(
+            "Method checking whether [`{}::{}`] field is either `None` or an empty vector.\n",
+            struct_name,
+            getter_field.name_string()
+        ));
+        res.push(GetterFn {
+            span: field.span(),
+            name: fn_name.clone(),
+            arg: "is_empty",
+            vis: quote! { pub },
+            cfg: cfg.clone(),
+            attrs: quote! { #fn_doc #deprecated },
+            impl_attrs: impl_attrs.clone(),
+            constness: quote! {},
+            sig: quote_spanned! { field.span() => fn #fn_name(&self) -> bool },
+            body: getter.guarded_body(
+                struct_name,
+                &field_label,
+                &fn_name,
+                quote_spanned! { field.span() =>
+                    match self.#field_access {
+                        ::core::option::Option::None => true,
+                        ::core::option::Option::Some(ref vec) => vec.is_empty(),
+                    }
+                },
+            ),
+        })
+    }
+
+    if let Some(ref suffix) = getter.entry {
+        let (key_ty, value_ty, hasher_ty) = match map_key_value(ty) {
+            Some(kv) => kv,
+            None => {
+                return Err(Error::new_spanned(
+                    ty,
+                    "`entry` getter argument requires field to be of `HashMap<_, _>` type",
+                ));
+            }
+        };
+        let fn_name = getter.compose_fn_ident(&suffix.value(), field_name, field.span())?;
+        let fn_doc = getter.synthesized_doc(format!(
+            "Method returning mutable borrow of the value under the given key in [`{}::{}`] \
+             field, inserting default value if the key is not present.\n",
+            struct_name,
+            getter_field.name_string()
+        ));
+        let hasher_bound = match hasher_ty {
+            Some(hasher_ty) => quote! { #hasher_ty: ::core::hash::BuildHasher, },
+            None => quote! {},
+        };
+        res.push(GetterFn {
+            span: field.span(),
+            name: fn_name.clone(),
+            arg: "entry",
+            vis: quote! { pub },
+            cfg: cfg.clone(),
+            attrs: quote! { #fn_doc #deprecated },
+            impl_attrs: impl_attrs.clone(),
+            constness: quote! {},
+            sig: quote_spanned! { field.span() =>
+                fn #fn_name(&mut self, key: #key_ty) -> &mut #value_ty
+                where
+                    #key_ty: ::core::cmp::Eq + ::core::hash::Hash,
+                    #value_ty: ::core::default::Default,
+                    #hasher_bound
+            },
+            body: getter.guarded_body(
+                struct_name,
+                &field_label,
+                &fn_name,
+                quote_spanned! { field.span() =>
+                    self.#field_access.entry(key).or_default()
+                },
+            ),
+        })
+    }
+
+    if let Some(ref suffix) = getter.set_if_changed {
+        let fn_name = getter.compose_fn_ident(&suffix.value(), field_name, field.span())?;
+        let fn_doc = getter.synthesized_doc(format!(
+            "Method assigning new value to [`{}::{}`] field if it differs from the current \
+             one.\n\nReturns whether the value has been changed.",
+            struct_name,
+            getter_field.name_string()
+        ));
+        res.push(GetterFn {
+            span: field.span(),
+            name: fn_name.clone(),
+            arg: "set_if_changed",
+            vis: quote! { pub },
+            cfg: cfg.clone(),
+            attrs: quote! { #fn_doc #deprecated },
+            impl_attrs: impl_attrs.clone(),
+            constness: quote! {},
+            sig: quote_spanned! { field.span() =>
+                fn #fn_name(&mut self, value: #ty) -> bool
+                where
+                    #ty: ::core::cmp::PartialEq,
+            },
+            body: getter.guarded_body(
+                struct_name,
+                &field_label,
+                &fn_name,
+                quote_spanned! { field.span() =>
+                    if self.#field_access != value {
+                        self.#field_access = value;
+                        true
+                    } else {
+                        false
+                    }
+                },
+            ),
+        })
+    }
+
+    if getter.observable {
+        let on_change = getter.on_change.as_ref().ok_or_else(|| {
+            Error::new(
+                field.span(),
+                "`observable` getter argument requires callback to be provided with \
+                 `#[getter(on_change = \"...\")]` attribute on the struct",
+            )
+        })?;
+        let fn_name = fn_ident(
+            &format!("set_{}", getter.base_string(field_name, field.span())?),
+            field.span(),
+        )?;
+        let name = getter_field.name_string();
+        let fn_doc = getter.synthesized_doc(format!(
+            "Method assigning new value to [`{}::{}`] field and notifying about the change.\n",
+            struct_name, name
+        ));
+        res.push(GetterFn {
+            span: field.span(),
+            name: fn_name.clone(),
+            arg: "observable",
+            vis: quote! { pub },
+            cfg: cfg.clone(),
+            attrs: quote! { #fn_doc #deprecated },
+            impl_attrs: impl_attrs.clone(),
+            constness: quote! {},
+            sig: quote_spanned! { field.span() => fn #fn_name(&mut self, value: #ty) },
+            body: getter.guarded_body(
+                struct_name,
+                &field_label,
+                &fn_name,
+                quote_spanned! { field.span() =>
+                    self.#field_access = value;
+                    (#on_change)(#name);
+                },
+            ),
+        })
+    }
+
+    // Forwarding methods are named after the field unless a custom prefix is
+    // given, since the inner getters may clash with the outer ones
+    let flatten_prefix = match getter.flatten_prefix {
+        Some(ref prefix) => prefix.value(),
+        None if !getter.flatten.is_empty() => {
+            format!("{}_", getter.base_string(field_name, field.span())?)
+        }
+        None => String::new(),
+    };
+    for (name, ret_ty) in &getter.flatten {
+        let fn_name = fn_ident(&format!("{}{}", flatten_prefix, name.unraw()), name.span())?;
+        let fn_doc = getter.synthesized_doc(format!(
+            "Method forwarding to `{}` getter of [`{}::{}`] field.\n",
+            name,
+            struct_name,
+            getter_field.name_string()
+        ));
+        res.push(GetterFn {
+            span: name.span(),
+            name: fn_name.clone(),
+            arg: "flatten",
+            vis: quote! { pub },
+            cfg: cfg.clone(),
+            attrs: quote! { #fn_doc #deprecated },
+            impl_attrs: impl_attrs.clone(),
+            constness: quote! {},
+            sig: quote_spanned! { name.span() => fn #fn_name(&self) -> #ret_ty },
+            body: getter.guarded_body(
+                struct_name,
+                &field_label,
+                &fn_name,
+                quote_spanned! { name.span() =>
+                    self.#field_access.#name()
+                },
+            ),
+        })
+    }
+
+    Ok(res)
+}
 
-        res.push(quote_spanned! { field.span() =>
+fn derive_variant_methods(
+    variant: &Variant,
+    enum_name: &Ident,
+    global_param: &ParametrizedAttr,
+    type_accessor: &HashMap<String, ArgValue>,
+    inherited: &mut Option<GetterDerive>,
+    other_variants: bool,
+) -> Result<Vec<TokenStream2>> {
+    let getter = local_getter(&variant.attrs, global_param, type_accessor, inherited)?;
+    if let Some(arg) = getter.struct_only_arg() {
+        return Err(Error::new_spanned(
+            variant,
+            format!("`{}` getter argument is supported only in structs", arg),
+        ));
+    }
+
+    if getter.skip {
+        return Ok(Vec::new());
+    }
+
+    let ty = match variant.fields {
+        Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+        _ => {
+            return Err(Error::new_spanned(
+                variant,
+                "Deriving getters on enums requires all variants to have a single unnamed field; \
+                 use `#[getter(skip)]` to skip other variants",
+            ));
+        }
+    };
+    if getter.as_str.is_some() && !is_str_like(ty) {
+        return Err(Error::new_spanned(
+            ty,
+            "`as_str` getter argument requires field to be of `String`, `Box<str>`, `Rc<str>` or \
+             `Arc<str>` type",
+        ));
+    }
+    if getter.as_slice.is_some() && slice_elem(ty).is_none() {
+        return Err(Error::new_spanned(
+            ty,
+            "`as_slice` getter argument requires field to be of `Vec<_>` or array type",
+        ));
+    }
+    let coverage = getter.coverage_attr();
+    let inline = &getter.inline;
+    let allow = &getter.allow;
+    let variant_name = &variant.ident;
+    let base_name = Ident::new(
+        &RenameRule::Snake.apply(&variant_name.unraw().to_string()),
+        variant_name.span(),
+    );
+    let doc = variant
+        .attrs
+        .iter()
+        .filter(|a| a.path.is_ident("doc"))
+        .collect::<Vec<_>>();
+    let wildcard = if other_variants {
+        quote! { _ => ::core::option::Option::None, }
+    } else {
+        quote! {}
+    };
+
+    let mut res = Vec::with_capacity(3);
+    for method in getter.all_methods() {
+        let fn_name = getter.getter_fn_ident(method, Some(&base_name), variant.span())?;
+        let fn_doc = getter.getter_variant_doc(method, enum_name, variant_name, &doc);
+        let receiver = getter.receiver(method);
+        let fn_generics = getter.fn_generics(method);
+        let constness = getter.constness(method);
+        let ret_type = getter.ret_type(method, ty);
+        let where_bound = getter.where_bound(method, ty);
+        let (must_use, value) = match method {
+            GetterMethod::Main { copy: true } => (quote! { #[must_use] }, quote! { *inner }),
+            GetterMethod::Main { copy: false } => {
+                (quote! { #[must_use] }, quote! { ::core::clone::Clone::clone(inner) })
+            }
+            GetterMethod::AsRef | GetterMethod::AsMut => (quote! {}, quote! { inner }),
+            GetterMethod::AsDeref | GetterMethod::AsStr => {
+                (quote! {}, quote! { ::core::ops::Deref::deref(inner) })
+            }
+            GetterMethod::AsSlice => (quote! {}, quote! { &inner[..] }),
+            GetterMethod::Into => (quote! { #[must_use] }, quote! { inner }),
+            GetterMethod::AsInto => (quote! { #[must_use] }, method.ret_value(quote! { *inner })),
+            GetterMethod::ToOwned => (quote! { #[must_use] }, match ty {
+                Type::Reference(_) => quote! { ::std::borrow::ToOwned::to_owned(&**inner) },
+                _ => quote! { ::std::borrow::ToOwned::to_owned(inner) },
+            }),
+            GetterMethod::Pin => {
+                unreachable!("Internal inconsistency in getter derivation macro implementation")
+            }
+        };
+
+        let vis = getter.vis(method);
+        res.push(quote_spanned! { variant.span() =>
             #fn_doc
-            #[inline]
-            pub fn #fn_name(&#mut_prefix self) -> #ret_prefix #ty {
-                #ret_prefix self.#field_name #ret_suffix
+            #inline
+            #coverage
+            #allow
+            #must_use
+            #vis #constness fn #fn_name #fn_generics (#receiver) -> ::core::option::Option<#ret_type> #where_bound {
+                match self {
+                    #enum_name::#variant_name(inner) => ::core::option::Option::Some(#value),
+                    #wildcard
+                }
+            }
+        })
+    }
+
+    if let Some(ref prefix) = getter.try_into {
+        // Like consuming getters, the name is defined by the method prefix,
+        // which replaces the type-level one
+        let fn_name = match getter.rename {
+            Some(ref rename) => {
+                fn_ident(&format!("{}{}", prefix.value(), rename.unraw()), variant.span())?
+            }
+            None => {
+                let mut try_getter = getter.clone();
+                try_getter.prefix = prefix.clone();
+                try_getter.compose_fn_ident("", Some(&base_name), variant.span())?
+            }
+        };
+        let fn_doc = getter.synthesized_doc(format!(
+            "Method moving out inner data of [`{}::{}`] variant, if the value matches it, or \
+             returning the value back as an error otherwise.\n",
+            enum_name, variant_name
+        ));
+        let since_doc = getter.since_doc();
+        let wildcard = if other_variants {
+            quote! { other => ::core::result::Result::Err(other), }
+        } else {
+            quote! {}
+        };
+        res.push(quote_spanned! { variant.span() =>
+            #fn_doc
+            #( #doc )*
+            #since_doc
+            #inline
+            #coverage
+            #allow
+            pub fn #fn_name(self) -> ::core::result::Result<#ty, Self> {
+                match self {
+                    #enum_name::#variant_name(inner) => ::core::result::Result::Ok(inner),
+                    #wildcard
+                }
             }
         })
     }
 
     Ok(res)
 }
+
+/// Reports arguments of `#[getter]` attribute which are not known at the
+/// given level, suggesting the closest known argument in case of a typo. If
+/// there are several unknown arguments, the first one by name is reported, so
+/// the error doesn't depend on the order of the parsed arguments map.
+fn check_unknown_args(
+    attr: &ParametrizedAttr,
+    known: &HashMap<&'static str, ArgValueReq>,
+    level: AttrLevel,
+) -> Result<()> {
+    let spans = arg_spans(attr);
+    let unknown = attr
+        .paths
+        .iter()
+        .filter_map(|path| path.get_ident())
+        .map(|ident| (ident.to_string(), ident.span()))
+        .chain(attr.args.keys().map(|name| {
+            let span = spans.get(name).copied().unwrap_or_else(Span::call_site);
+            (name.clone(), span)
+        }))
+        .filter(|(name, _)| !known.contains_key(name.as_str()))
+        .min_by(|(a, _), (b, _)| a.cmp(b));
+    let (name, span) = match unknown {
+        Some(unknown) => unknown,
+        None => return Ok(()),
+    };
+
+    let msg = match level {
+        AttrLevel::Field if GetterDerive::arg_reqs(AttrLevel::Type).contains_key(name.as_str()) => {
+            format!("`{}` getter argument can be used only at type level", name)
+        }
+        AttrLevel::Type if GetterDerive::arg_reqs(AttrLevel::Field).contains_key(name.as_str()) => {
+            format!("`{}` getter argument can be used only at field level", name)
+        }
+        _ => {
+            let mut candidates = known
+                .keys()
+                .map(|candidate| (edit_distance(&name, candidate), *candidate))
+                .filter(|(distance, _)| *distance <= (name.len() / 3).max(1))
+                .collect::<Vec<_>>();
+            candidates.sort_unstable();
+            match candidates.first() {
+                Some((_, candidate)) => {
+                    format!("unknown getter argument `{}`; did you mean `{}`?", name, candidate)
+                }
+                None => format!("unknown getter argument `{}`", name),
+            }
+        }
+    };
+    Err(Error::new(span, msg))
+}
+
+/// Reports arguments given a value of wrong class or missing a required value
+fn check_arg_values(
+    attr: &ParametrizedAttr,
+    known: &HashMap<&'static str, ArgValueReq>,
+) -> Result<()> {
+    for ident in attr.paths.iter().filter_map(|path| path.get_ident()) {
+        let name = ident.to_string();
+        match known.get(name.as_str()) {
+            Some(req)
+                if req.value_class() == Some(ValueClass::str()) &&
+                    matches!(req.default_value(), ArgValue::None) =>
+            {
+                return Err(Error::new(
+                    ident.span(),
+                    format!(
+                        "`{}` getter argument requires string literal value, like `{} = \"...\"`",
+                        name, name
+                    ),
+                ));
+            }
+            _ => {}
+        }
+    }
+    for (name, value) in &attr.args {
+        let class = match known.get(name.as_str()).and_then(ArgValueReq::value_class) {
+            Some(class) => class,
+            None => continue,
+        };
+        if class != ValueClass::str() || value.value_class() == Some(class) {
+            continue;
+        }
+        let (given, span) = match value {
+            ArgValue::Literal(lit) => {
+                let given = match lit {
+                    Lit::ByteStr(_) => "byte string literal",
+                    Lit::Byte(_) => "byte literal",
+                    Lit::Char(_) => "character literal",
+                    Lit::Int(_) => "integer literal",
+                    Lit::Float(_) => "float literal",
+                    Lit::Bool(_) => "boolean literal",
+                    _ => "literal",
+                };
+                (given, lit.span())
+            }
+            ArgValue::Type(ty) => ("type or path", ty.span()),
+            ArgValue::Expr(expr) => ("expression", expr.span()),
+            ArgValue::None => continue,
+        };
+        return Err(Error::new(
+            span,
+            format!(
+                "`{}` getter argument requires string literal value, like `{} = \"...\"`, but {} \
+                 is given",
+                name, name, given
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Spans of the attribute arguments: of the names for the arguments without
+/// values and of the values for the others
+fn arg_spans(attr: &ParametrizedAttr) -> HashMap<String, Span> {
+    let mut spans = attr
+        .paths
+        .iter()
+        .filter_map(|path| path.get_ident())
+        .map(|ident| (ident.to_string(), ident.span()))
+        .collect::<HashMap<_, _>>();
+    for (name, value) in &attr.args {
+        let span = match value {
+            ArgValue::Literal(lit) => lit.span(),
+            ArgValue::Type(ty) => ty.span(),
+            ArgValue::Expr(expr) => expr.span(),
+            ArgValue::None => continue,
+        };
+        spans.insert(name.clone(), span);
+    }
+    spans
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev + usize::from(ca != *cb);
+            prev = row[j + 1];
+            row[j + 1] = substitution.min(prev + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Parses receiver type given to `self_ty` argument, which must be a reference
+/// to a type mentioning `Self`, since the getters return borrows of the
+/// receiver
+fn self_ty_arg(lit: &LitStr) -> Result<Type> {
+    fn mentions_self(tokens: TokenStream2) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => ident == "Self",
+            TokenTree::Group(group) => mentions_self(group.stream()),
+            _ => false,
+        })
+    }
+
+    let ty = lit.parse::<Type>()?;
+    match ty {
+        Type::Reference(ref reference) if mentions_self(reference.elem.to_token_stream()) => Ok(ty),
+        _ => Err(Error::new(
+            lit.span(),
+            "`self_ty` getter argument requires reference to a type wrapping `Self`, like \
+             `&Arc<Self>`",
+        )),
+    }
+}
+
+/// Parses argument value which must be a configuration predicate
+fn cfg_predicate(arg: &str, lit: &LitStr) -> Result<Meta> {
+    lit.parse().map_err(|err| {
+        Error::new(
+            lit.span(),
+            format!(
+                "`{}` getter argument must be a configuration predicate, like `feature = \
+                 \"accessors\"`; {}",
+                arg, err
+            ),
+        )
+    })
+}
+
+/// Parses argument value which must be a valid Rust identifier
+fn ident_arg(arg: &str, lit: &LitStr) -> Result<Ident> {
+    lit.parse::<Ident>().map_err(|_| {
+        Error::new(
+            lit.span(),
+            format!(
+                "`{}` getter argument must be a valid Rust identifier, while `{}` is not",
+                arg,
+                lit.value()
+            ),
+        )
+    })
+}
+
+/// Constructs identifier of the derived method, checking that the composed
+/// name is a valid Rust identifier
+fn fn_ident(name: &str, span: Span) -> Result<Ident> {
+    try_ident(name, span).ok_or_else(|| {
+        Error::new(
+            span,
+            format!("derived getter method name `{}` is not a valid Rust identifier", name),
+        )
+    })
+}
+
+/// Parses list of delegated methods in form of `name: Type, ...`
+fn parse_delegates(arg: &str, lit: &LitStr) -> Result<Vec<(Ident, Type)>> {
+    let parser = |input: ParseStream| {
+        let mut delegates = vec![];
+        while !input.is_empty() {
+            let name: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+            let ty: Type = input.parse()?;
+            delegates.push((name, ty));
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(delegates)
+    };
+    lit.parse_with(parser).map_err(|err| {
+        Error::new(
+            lit.span(),
+            format!(
+                "`{}` must contain comma-separated list of `method: ReturnType` items; {}",
+                arg, err
+            ),
+        )
+    })
+}
+
+/// Parses comma-separated list of items given in a string argument value,
+/// like `only = "name, port"`. Since the attributes do not support nested
+/// lists, this is the way list-valued arguments are provided.
+fn parse_list<T: Parse>(arg: &str, items: &str, lit: &LitStr) -> Result<Vec<T>> {
+    lit.parse_with(Punctuated::<T, Token![,]>::parse_terminated)
+        .map(|list| list.into_iter().collect())
+        .map_err(|err| {
+            Error::new(
+                lit.span(),
+                format!("`{}` must contain comma-separated list of {}; {}", arg, items, err),
+            )
+        })
+}
+
+/// Detects whether member refers to the field with the given index
+fn is_member(member: &Member, index: usize, field: &Field) -> bool {
+    match (member, &field.ident) {
+        (Member::Named(name), Some(ident)) => name == ident,
+        (Member::Unnamed(member), None) => member.index as usize == index,
+        _ => false,
+    }
+}
+
+/// Parses comma-separated list of rustdoc aliases
+fn parse_doc_aliases(lit: &LitStr) -> Result<Vec<LitStr>> {
+    lit.value()
+        .split(',')
+        .map(|alias| match alias.trim() {
+            "" => Err(Error::new(
+                lit.span(),
+                "`doc_alias` must contain comma-separated list of non-empty aliases",
+            )),
+            alias => Ok(LitStr::new(alias, lit.span())),
+        })
+        .collect()
+}
+
+/// Parses comma-separated list of lint paths, like `clippy::clone_on_copy`
+fn parse_allow_lints(lit: &LitStr) -> Result<Punctuated<Path, Token![,]>> {
+    let lints = lit
+        .parse_with(Punctuated::<Path, Token![,]>::parse_terminated)
+        .map_err(|err| {
+            Error::new(
+                lit.span(),
+                format!(
+                    "`allow` must contain comma-separated list of lint paths, like \
+                     `clippy::must_use_candidate`; {}",
+                    err
+                ),
+            )
+        })?;
+    if lints.is_empty() {
+        return Err(Error::new(lit.span(), "`allow` must contain at least one lint path"));
+    }
+    Ok(lints)
+}
+
+/// Parses semicolon-separated list of field groups in form of
+/// `name: field1, field2`
+fn parse_groups(lit: &LitStr) -> Result<Vec<(Ident, Vec<Ident>)>> {
+    let group = |input: ParseStream| {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let members = Punctuated::<Ident, Token![,]>::parse_separated_nonempty(input)?;
+        Ok((name, members.into_iter().collect::<Vec<_>>()))
+    };
+    let groups = lit
+        .parse_with(|input: ParseStream| {
+            Punctuated::<_, Token![;]>::parse_terminated_with(input, group)
+        })
+        .map_err(|err| {
+            Error::new(
+                lit.span(),
+                format!(
+                    "`group` must contain semicolon-separated list of method names with the \
+                     fields, like `coords: x, y`; {}",
+                    err
+                ),
+            )
+        })?;
+    groups
+        .into_iter()
+        .map(|(name, members)| match members.len() {
+            0 | 1 => Err(Error::new(
+                lit.span(),
+                format!("`group` getter argument requires at least two fields in `{}` group", name),
+            )),
+            _ => Ok((name, members)),
+        })
+        .collect()
+}
+
+/// Parses names of the success value and error fields in form of
+/// `value, error`
+fn parse_result_fields(lit: &LitStr) -> Result<(Ident, Ident)> {
+    let parser = |input: ParseStream| {
+        let value: Ident = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let error: Ident = input.parse()?;
+        Ok((value, error))
+    };
+    lit.parse_with(parser).map_err(|err| {
+        Error::new(
+            lit.span(),
+            format!(
+                "`as_result` must contain names of the value and error fields separated with \
+                 comma; {}",
+                err
+            ),
+        )
+    })
+}
+
+/// Splits identifier into words on underscores and case boundaries (like in
+/// `CamelCase`, `snake_case` and `HTTPError`)
+fn split_words(s: &str) -> Vec<String> {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut words = vec![];
+    let mut word = String::new();
+    for (pos, c) in chars.iter().enumerate() {
+        if *c == '_' {
+            if !word.is_empty() {
+                words.push(word);
+                word = String::new();
+            }
+            continue;
+        }
+        if c.is_uppercase() && pos > 0 && !word.is_empty() {
+            let prev = chars[pos - 1];
+            let next_lower = chars
+                .get(pos + 1)
+                .map(|c| c.is_lowercase())
+                .unwrap_or_default();
+            if !prev.is_uppercase() || next_lower {
+                words.push(word);
+                word = String::new();
+            }
+        }
+        word.push(*c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Detects types dereferencing into `str`: `String`, `Box<str>`, `Rc<str>` and
+/// `Arc<str>`
+fn is_str_like(ty: &Type) -> bool {
+    match type_last_segment(ty) {
+        Some(segment) if segment.ident == "String" => {
+            matches!(segment.arguments, PathArguments::None)
+        }
+        Some(segment)
+            if ["Box", "Rc", "Arc"]
+                .iter()
+                .any(|name| segment.ident == name) =>
+        {
+            match generic_type_args(segment)[..] {
+                [inner] => type_last_segment(inner)
+                    .map(|inner| inner.ident == "str")
+                    .unwrap_or_default(),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns trait object type of `Box<dyn Trait>` types, parenthesized if it has
+/// multiple bounds (like `(dyn Trait + Send)`), so it can be borrowed
+fn boxed_trait_object(ty: &Type) -> Option<TokenStream2> {
+    let segment = type_last_segment(ty).filter(|segment| segment.ident == "Box")?;
+    match generic_type_args(segment)[..] {
+        [Type::TraitObject(object)] if object.bounds.len() > 1 => Some(quote! { (#object) }),
+        [Type::TraitObject(object)] => Some(quote! { #object }),
+        _ => None,
+    }
+}
+
+/// Returns type which is turned into owned data by `to_owned` getters: the
+/// referenced type for the reference fields (like `str` for `&'a str`) and the
+/// field type itself otherwise
+fn owned_source(ty: &Type) -> &Type {
+    match ty {
+        Type::Reference(reference) => &reference.elem,
+        _ => ty,
+    }
+}
+
+/// Returns element type of `Vec<T>` and `[T; N]` types, which are borrowed as
+/// `&[T]` slices
+fn slice_elem(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Array(array) => Some(&array.elem),
+        _ => type_last_segment(ty)
+            .filter(|segment| segment.ident == "Vec")
+            .and_then(|segment| match generic_type_args(segment)[..] {
+                [elem] => Some(elem),
+                _ => None,
+            }),
+    }
+}
+
+/// Detects types which values are `#[must_use]` for the purposes of getters
+/// returning them by value.
+fn is_must_use_type(ty: &Type) -> bool {
+    type_last_segment(ty)
+        .map(|segment| segment.ident == "Result" || segment.ident == "Option")
+        .unwrap_or_default()
+}
@@ -27,10 +27,12 @@ extern crate proc_macro;
 mod util;
 
 mod as_any;
+mod default;
 mod display;
 mod error;
 mod from;
 mod getters;
+mod setters;
 mod wrapper;
 
 use proc_macro::TokenStream;
@@ -152,6 +154,18 @@ use syn::DeriveInput;
 ///    ```
 ///    You can also mix in this mode with other fors of display tags on a
 ///    specific options; in this case doc comments are ignored
+///
+///    Each variant displayed with doc comments must have ones:
+///    ```compile_fail
+///     # #[macro_use] extern crate amplify_derive;
+///     #[derive(Display)]
+///     #[display(doc_comments)]
+///     enum Status {
+///         /// Everything is fine
+///         Ok,
+///         Failed,
+///     }
+///    ```
 /// 6. Support of unit structs and newtypes:
 ///    ```
 ///     # #[macro_use] extern crate amplify_derive;
@@ -255,6 +269,39 @@ use syn::DeriveInput;
 /// assert_eq!(format!("{}", Tuple(5)), format!("{}", Tuple2(5)))
 /// ```
 ///
+/// With structs `inner` forwards formatting to the field `Display`
+/// implementation, so the format spec (width, alignment, precision) applies to
+/// the inner value. Structures with multiple fields must name the displayed
+/// field (or the index of a tuple field) with `inner = "field"`:
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Display)]
+/// #[display(inner)]
+/// struct Amount(f64);
+///
+/// #[derive(Display)]
+/// #[display(inner = "name")]
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// assert_eq!(format!("{:>8}", Amount(1.5)), "     1.5");
+/// assert_eq!(format!("{:.2}", Amount(1.5)), "1.50");
+/// let user = User { id: 1, name: String::from("alice") };
+/// assert_eq!(format!("{:<7}|", user), "alice  |");
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Display)]
+/// #[display(inner)]
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+/// ```
+///
 /// Using inner enum variant representation, defaulting to the variant name
 /// if the variant does not have inner data:
 /// ```
@@ -309,7 +356,51 @@ pub fn derive_display(input: TokenStream) -> TokenStream {
 /// assert_eq!(format!("{}", Error::Overflow), "Math overflow");
 /// assert_eq!(format!("{}", Error::ZeroDivision(2)), "Zero division with 2");
 /// ```
-#[proc_macro_derive(Error)]
+///
+/// Field marked with `#[source]` attribute (or the only field of a variant
+/// marked with `#[source]`) is returned from [`std::error::Error::source`];
+/// variants without such field have no source. Fields converted with
+/// `#[from]` are not sources unless they are marked with `#[source]` too,
+/// since they may be of non-error types:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::error::Error as _;
+///
+/// #[derive(Debug, Display, Error, From)]
+/// #[display(doc_comments)]
+/// enum Error {
+///     /// I/O error: {0}
+///     #[from]
+///     #[source]
+///     Io(std::io::Error),
+///
+///     /// {0}
+///     #[from]
+///     Message(String),
+///
+///     /// invalid number {number}
+///     Parse {
+///         number: String,
+///         #[source]
+///         error: std::num::ParseIntError,
+///     },
+///
+///     /// unknown error
+///     Unknown,
+/// }
+///
+/// let io = std::io::Error::new(std::io::ErrorKind::Other, "disk failure");
+/// let err = Error::from(io);
+/// assert_eq!(err.source().unwrap().to_string(), "disk failure");
+///
+/// let error = "x".parse::<u8>().unwrap_err();
+/// let err = Error::Parse { number: String::from("x"), error: error.clone() };
+/// assert_eq!(err.source().unwrap().to_string(), error.to_string());
+/// assert!(Error::Unknown.source().is_none());
+/// assert!(Error::from(String::from("failure")).source().is_none());
+/// ```
+#[proc_macro_derive(Error, attributes(source, from))]
 pub fn derive_error(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
     error::inner(derive_input)
@@ -366,6 +457,35 @@ pub fn derive_error(input: TokenStream) -> TokenStream {
 /// pub struct Wrapper(u32, i16);
 /// ```
 ///
+/// Enum variants with a single field and without explicit `#[from]` attributes
+/// get conversion from the field type, unless they are marked with
+/// `#[from(skip)]`. Variants wrapping the same type must be disambiguated with
+/// `#[from(skip)]`, otherwise the compilation fails:
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Debug, From)]
+/// pub enum Error {
+///     Io(::std::io::Error),
+///     Utf8 { source: ::std::str::Utf8Error },
+///     Parse(::std::num::ParseIntError),
+///     #[from(skip)]
+///     Range(::std::num::ParseIntError),
+///     Unknown,
+/// }
+///
+/// let err = Error::from("10a".parse::<u8>().unwrap_err());
+/// assert!(matches!(err, Error::Parse(_)));
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(From)]
+/// pub enum Error {
+///     Parse(::std::num::ParseIntError),
+///     Range(::std::num::ParseIntError),
+/// }
+/// ```
+///
 /// If you use rust nightly and `#![feature(never_type)]` for [`!`], you can
 /// even do the following:
 /// ```ignore
@@ -392,6 +512,69 @@ pub fn derive_from(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Implements [`Default`] trait, allowing to provide custom initializer
+/// expressions for the fields with `#[default = "expr"]` attribute; fields
+/// without the attribute are initialized with `Default::default()`. For enums
+/// the default variant must be marked with `#[default]` attribute; its fields,
+/// if any, are initialized in the same way as structure fields.
+///
+/// Like the `std` derive macro, the implementation requires all type
+/// parameters to implement `Default`.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::time::Duration;
+///
+/// #[derive(Default, PartialEq, Debug)]
+/// struct Config {
+///     #[default = "Duration::from_secs(30)"]
+///     timeout: Duration,
+///     #[default = "String::from(\"localhost\")"]
+///     host: String,
+///     #[default = "8080"]
+///     port: u16,
+///     verbose: bool,
+/// }
+///
+/// #[derive(Default, PartialEq, Debug)]
+/// enum Mode {
+///     Off,
+///     #[default]
+///     Retry {
+///         #[default = "3"]
+///         attempts: u8,
+///         delay: u64,
+///     },
+/// }
+///
+/// let config = Config::default();
+/// assert_eq!(config.timeout, Duration::from_secs(30));
+/// assert_eq!(config.host, "localhost");
+/// assert_eq!(config.port, 8080);
+/// assert!(!config.verbose);
+/// assert_eq!(Mode::default(), Mode::Retry { attempts: 3, delay: 0 });
+/// ```
+///
+/// Invalid initializer expressions are reported at the attribute:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Default)]
+/// struct Config {
+///     #[default = "8080 +"]
+///     port: u16,
+/// }
+/// ```
+#[proc_macro_derive(Default, attributes(default))]
+pub fn derive_default(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    default::inner(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 /// Trait `amplify::AsAny` allows simple conversion of any type into a
 /// generic "thick" pointer `&dyn Any` (see [`::core::any::Any`]), that can be
 /// later converted back to the original type with a graceful failing for all
@@ -433,6 +616,30 @@ pub fn derive_from(input: TokenStream) -> TokenStream {
 /// let p = point_ptr.downcast_ref::<Point>().unwrap();
 /// assert_eq!(p.x, 1)
 /// ```
+///
+/// Enums and generic types are supported as well; generic types implement the
+/// trait only when they are `'static`, which is required for the conversion
+/// into `&dyn Any`:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// extern crate amplify;
+/// // Importing the trait only, since `amplify` re-exports derive macros as well
+/// use amplify::AsAny as _;
+///
+/// #[derive(AsAny, PartialEq, Debug)]
+/// enum Shape<T> {
+///     Dot,
+///     Segment(T, T),
+/// }
+///
+/// let segment = Shape::Segment(1u8, 2u8);
+/// let plugins: Vec<&dyn amplify::AsAny> = vec![&segment, &5u8];
+/// assert_eq!(plugins[0].as_any().downcast_ref::<Shape<u8>>(), Some(&segment));
+/// assert_eq!(plugins[0].as_any().downcast_ref::<Shape<u16>>(), None);
+/// assert_eq!(plugins[1].as_any().downcast_ref::<u8>(), Some(&5));
+/// assert_ne!(Shape::<u8>::Dot.as_any().downcast_ref(), Some(&segment));
+/// ```
 #[proc_macro_derive(AsAny)]
 pub fn derive_as_any(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -441,13 +648,23 @@ pub fn derive_as_any(input: TokenStream) -> TokenStream {
         .into()
 }
 
-/// Derives getter methods for structures. The return type and naming of the
-/// methods depends on the provided attribute arguments.
+/// Derives getter methods for structures and enums. The return type and naming
+/// of the methods depends on the provided attribute arguments.
+///
+/// Conditional compilation attributes of the fields (`#[cfg(...)]`, as well as
+/// `#[cfg_attr(...)]` applying `doc`, `deprecated`, `must_use` or lint
+/// attributes) are forwarded to the methods derived for them. So are
+/// `#[deprecated(...)]` attributes of the fields, keeping their `since` and
+/// `note`, unless the alias has its own deprecation from `deprecated_alias`.
+/// Since the compiler evaluates `#[cfg_attr(...)]` before invoking the derive
+/// macro, the methods get the conditional attributes only if their predicate
+/// holds, exactly as the fields do.
 ///
 /// # Attribute `#[getter(...)]`
 ///
 /// Macro is provided with `#[getter]` attribute, which may be used on both
-/// type and field level. See following sections describing its arguments
+/// type and field (or enum variant) level. See following sections describing
+/// its arguments
 ///
 /// ## Arguments
 ///
@@ -464,19 +681,179 @@ pub fn derive_as_any(input: TokenStream) -> TokenStream {
 /// - `as_clone`: derives methods returning cloned value; will conflict with
 ///   `as_copy`. Errors at compile time on types which does not implement
 ///   `Clone`.
+/// - `main`: derives method returning cloned value, like `as_clone`, but with
+///   explicit `Clone` bound on the field type, so types which can't be cloned
+///   are reported at the field declaration. Conflicts with `as_copy` and
+///   `as_clone`.
 /// - `as_ref`: derives method returning reference. If provided together with
 ///   either `as_copy` or `as_clone`, method name returning reference is
-///   suffixed with `_ref`; otherwise the base name is used (see below)
+///   suffixed with `_ref`; otherwise the base name is used (see below).
+///   For fields which are references themselves the method returns copy of
+///   the reference (for `&'a T` fields, keeping its lifetime `'a`) or reborrow
+///   (`&T` for `&'a mut T` fields) instead of reference to the reference.
+///   Boxed trait objects (`Box<dyn Trait>` fields) are borrowed as
+///   `&dyn Trait`, while `as_mut` still returns `&mut Box<dyn Trait>`
 /// - `as_mut`: derives method returning mutable reference. Method name is
 ///   suffixed with `_mut`
+/// - `as_deref`: derives method returning reference to the dereferenced field
+///   value, i.e. `&<T as Deref>::Target`, like `&str` for `String`, `&[T]` for
+///   `Vec<T>` or `&T` for `Box<T>`. Method name is suffixed with `_deref`
+///   (unless other suffix is provided as the argument value); the field type
+///   must implement `Deref`
+/// - `as_str`: derives method returning `&str` for fields of `String`,
+///   `Box<str>`, `Rc<str>` and `Arc<str>` types; errors on other types. Method
+///   name is suffixed with `_str`
+/// - `as_slice`: derives method returning `&[T]` slice for fields of `Vec<T>`
+///   and `[T; N]` array types; errors on other types. Method name is suffixed
+///   with `_slice`
+/// - `into`: derives method consuming the value and returning the owned
+///   field. Method name is composed of `into_` prefix (or the one given as the
+///   argument value, which replaces the type-level `prefix`) and the base name.
+///   Consuming getters are supported only in structs with a single field (and
+///   enums, where they return `Option`), since otherwise they would drop the
+///   rest of the fields
+/// - `as_into`: derives method returning clone of the field value converted
+///   into the type provided with `into_type` argument (which is required for
+///   `as_into`), like `#[getter(as_into, into_type = "u64")]`. Errors at
+///   compile time on types which do not implement `Clone` and `Into` the
+///   target type. Method name is suffixed with `_into`
+/// - `to_owned`: derives method returning owned copy of the field data via
+///   [`ToOwned`], i.e. `fn field_owned(&self) -> <T as ToOwned>::Owned`. For
+///   reference fields the referenced data is copied, so `&'a str` field gives
+///   `String` and `&'a [T]` gives `Vec<T>`. Method name is suffixed with
+///   `_owned`; never implied by `all`
+/// - `pin`: derives method projecting pinned struct into pinned reference to
+///   the field, i.e. `fn field_pin(self: Pin<&Self>) -> Pin<&T>`. Method name
+///   is suffixed with `_pin`. Structs only; never implied by `all` and can't
+///   be combined with `as_mut`. **Safety**: the projection is implemented
+///   with `unsafe` [`core::pin::Pin::map_unchecked`], so the field must be
+///   structurally pinned: the struct must not move the field out while being
+///   pinned (including in its `Drop` implementation), must not provide other
+///   mutable access to it, and must not be `Unpin` unless the field type is
+///   `Unpin`
 /// - `all`: equivalent to `as_clone, as_ref, as_mut`
 ///
+/// Methods returning copy or clone of `Result` and `Option` fields are always
+/// marked with `#[must_use]`; for other field types this can be requested with
+/// `must_use` argument (see below).
+///
+/// For the fields which types depend on the struct type parameters, the
+/// derived `as_copy` getters get `Copy` bound and `as_clone` and `main` getters
+/// get `Clone` bound on the field types, unless the struct already declares
+/// them. The bounds are put on the methods themselves, so neither the struct
+/// nor the other getters are affected.
+///
+/// With additional `option` argument, methods borrowing fields of `Option<T>`
+/// type return `Option<&T>` (for `as_ref`) and `Option<&mut T>` (for `as_mut`)
+/// instead of references to the `Option` itself. Fields of other types are not
+/// affected. Not supported in enums.
+///
 /// **Can be used**: at type and field level
 ///
 /// **Defaults to**: `as_ref`
 ///
-/// ### `#[getter(skip)]`
-/// Skips derivation of a all gettter methods for this field
+/// ### `#[getter(must_use)]`
+/// Marks methods returning copy or clone of the field value (`as_copy`,
+/// `as_clone` and `main`) with `#[must_use]`. Errors if the only method
+/// derived for a field is `as_mut`.
+///
+/// **Can be used**: at type and field level
+///
+/// ### Method visibility arguments
+/// Derived methods are `pub` by default. Visibility of all the methods can be
+/// changed with `vis` argument, and of each method kind with `main_vis` (for
+/// `as_copy`, `as_clone` and `main`), `ref_vis`, `mut_vis`, `deref_vis`,
+/// `str_vis`, `slice_vis`, `into_vis`, `as_into_vis`, `owned_vis` and
+/// `pin_vis` arguments, which take precedence over `vis`. The arguments take
+/// visibility, like `#[getter(mut_vis = "pub(crate)")]`; an empty string
+/// makes the method private. Aliases get visibility of the method they
+/// alias. Can't be used together with `trait`.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[getter(inline = "...")]`
+/// Controls inlining hint of the derived methods: `inline` adds `#[inline]`,
+/// `always` adds `#[inline(always)]` and `none` omits the attribute, which may
+/// be preferable for large cloning getters or when optimizing for code size.
+/// Other values are rejected.
+///
+/// **Defaults to**: `inline`
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[getter(allow = "...")]`
+/// Adds `#[allow(...)]` attribute with the provided comma-separated list of
+/// lints to the derived getter methods, like
+/// `allow = "clippy::must_use_candidate"`, keeping the lints quiet on the
+/// generated code without allowing them for the whole module. Lint names must
+/// be valid paths. Field-level value replaces the type-level one.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[getter(forward_attr = "...")]`
+/// Copies field attributes with the given names to the derived getter methods,
+/// which allows integrating the getters with external tools reading the
+/// attributes of the methods. Takes comma-separated list of attribute names,
+/// like `forward_attr = "serde, my_tool"`; attributes are matched by their
+/// single-segment paths. The attributes must be valid on methods.
+/// Field-level value replaces the type-level one.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[getter(clone_on_copy_warn)]`
+/// Emits deprecation warning for the fields of `Copy` types which getters
+/// return clone of the value (`as_clone` and `main`), suggesting to use
+/// `as_copy` instead. Fields which types depend on the struct type parameters
+/// are not checked.
+///
+/// **Can be used**: at type and field level; structs only
+///
+/// ### `#[getter(const)]`
+/// Makes copying getter methods `const fn`, allowing their use in const
+/// contexts. Requires `as_copy` method argument and can't be combined with any
+/// other method arguments, since the methods derived by them can't be `const`.
+/// Being used at field level, applies to the methods inherited from the type
+/// level.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[getter(explicit_generics)]`
+/// Spells out the lifetime of the returned borrow in the signatures of the
+/// methods returning references (`as_ref`, `as_mut`, `as_deref`, `as_str` and
+/// `as_slice`),
+/// i.e. derives `fn name<'getter>(&'getter self) -> &'getter T` instead of
+/// relying on lifetime elision. The generic parameters of the type are taken
+/// from the `impl` block of the derived methods. Helps reading the generated
+/// code and the compiler diagnostics for types with many generic parameters.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[getter(self_ty = "...")]`
+/// Overrides the receiver of the methods returning borrows (`as_ref`,
+/// `as_deref`, `as_str` and `as_slice`), i.e. derives
+/// `fn name(self: &Arc<Self>) -> &T` for `self_ty = "&Arc<Self>"`. The type
+/// must be a reference to a type mentioning `Self`, since the returned borrow
+/// can't outlive the receiver; with `explicit_generics` the reference gets the
+/// `'getter` lifetime unless it is given explicitly. The rest of the methods
+/// keep the default receiver.
+///
+/// **Can be used**: at type and field level; structs only
+///
+/// ### `#[getter(skip)]` and `#[getter(expose)]`
+/// `skip` skips derivation of a all gettter methods for this field. At type
+/// level, it skips all fields (or enum variants) except the ones marked with
+/// `expose`, which get the methods defined by the rest of the type- and
+/// field-level arguments. Field-level `skip` always wins and can't be combined
+/// with `expose`.
+///
+/// **Can be used**: `skip` at type and field level, `expose` at field level
+///
+/// ### `#[getter(skip_main)]`, `#[getter(skip_ref)]` and `#[getter(skip_mut)]`
+/// Skip derivation of the individual methods for the field (main, `as_ref` and
+/// `as_mut` methods, correspondingly), keeping the rest of the methods defined
+/// by the type- and field-level arguments.
+///
+/// **Can be used**: at field level
 ///
 /// ### `#[getter(prefix = "...")]`
 /// Defines prefix added to all derived getter method names.
@@ -485,163 +862,3905 @@ pub fn derive_as_any(input: TokenStream) -> TokenStream {
 ///
 /// **Can be used**: at type level
 ///
+/// ### `#[getter(prefix_from_type)]`
+/// Uses snake-cased name of the last path segment of the field type followed
+/// by an underscore as the prefix, i.e. derives `config_inner()` for
+/// `inner: Config` and `http_client_inner()` for `inner: net::HttpClient`.
+/// Helps disambiguating accessors of large flattened structs. Fields of
+/// non-path types (like references or tuples) are rejected. Can't be combined
+/// with `prefix` at the same level; field-level `prefix` replaces type-level
+/// `prefix_from_type`.
+///
+/// **Can be used**: at type and field level; structs only
+///
+/// ### `#[getter(suffix = "...")]`
+/// Defines suffix added to the derived getter method names after the base name
+/// and before method-specific suffixes (like `_mut`). Being used at field
+/// level, overrides the type-level suffix.
+///
+/// **Defaults to**: none (no suffix added)
+///
+/// **Can be used**: at type and field level
+///
 /// ### `#[getter(base_name = "...")]`
 /// Defines base name for the getter method. Base name is prefixed with prefix
 /// from a type-level getter `prefix` attribute (if the one is specified) and
 /// suffix, which is method-specific (see `methods` argument description above).
+/// For named fields it replaces the field name, keeping prefix and suffixes,
+/// which makes it a less intrusive alternative to `rename`.
 ///
 /// **Defaults to**: field name
 ///
+/// **Required for**: unnamed fields of tuple structs, unless
+/// `prefix_numeric_fields` is used
+///
 /// **Can be used**: at field level
 ///
-/// # Errors
+/// ### `#[getter(prefix_numeric_fields)]`
+/// Uses `_0`, `_1` etc. as base names for the unnamed fields of tuple structs
+/// which are not equipped with `base_name` attribute.
 ///
-/// Enums and units are not supported; attempt to derive `Getters` on them will
-/// result in a compile-time error.
+/// **Can be used**: at type level; structs only
 ///
-/// Deriving getters on unit structs and structs with unnamed fields (tupe
-/// structs) is not supported (since it's meaningless), and results in a error.
+/// ### `#[getter(borrow_as = "...")]`
+/// Makes `as_ref` method return reference to the given type obtained with
+/// `AsRef`, like `&str` for `String` or `&Path` for `PathBuf` fields, instead
+/// of the reference to the field itself. The method gets `where Field:
+/// AsRef<Target>` bound, so it works with generic fields as well. Combined
+/// with `option`, borrows inner value of `Option` fields, returning
+/// `Option<&Target>`. Other methods are not affected.
 ///
-/// Additionally to these two cases, macro errors on argument inconsistencies,
-/// as described in the argument-specific sections.
+/// **Can be used**: at type and field level; structs only
 ///
-/// # Examples
+/// ### `#[getter(borrow)]`
+/// Additionally implements `Borrow` and `BorrowMut` traits for the field type,
+/// making the struct usable for the lookups in collections keyed by the field
+/// type. Can be used with a single field only; at type level it is meaningful
+/// for single-field structs. Keep in mind that `Borrow` requires `Eq`, `Ord`
+/// and `Hash` implementations of the struct to agree with those of the field.
 ///
-/// Basic use:
+/// **Can be used**: at type and field level; structs only
 ///
-/// ```
-/// # #[macro_use] extern crate amplify_derive;
-/// #[derive(Getters, Default)]
-/// struct One {
-///     vec: Vec<u8>,
-///     defaults: String,
-///     #[getter(as_copy)]
-///     pub flag: bool,
-///     #[getter(as_copy)]
-///     pub(self) field: u8,
-/// }
+/// ### `#[getter(rename = "...")]`
+/// Defines custom name for the getter method, which is used as-is, ignoring
+/// type-level `prefix`, `rename_all` and `rename_all_fields` attributes.
+/// Method-specific suffixes (or the prefix of `into` method) are still added to
+/// the name of the other derived methods. Must be a valid Rust identifier and
+/// can't be combined with `base_name`.
 ///
-/// let mut one = One::default();
-/// assert_eq!(one.vec(), &Vec::<u8>::default());
-/// assert_eq!(one.defaults(), "");
-/// assert_eq!(one.flag(), false);
-/// assert_eq!(one.field(), 0);
-/// ```
+/// With `keep_old`, the methods under the names which would be derived without
+/// `rename` are kept as deprecated (and hidden from the docs) shims forwarding
+/// to the renamed methods, easing migration of the downstream code.
+///
+/// **Can be used**: at field level; `keep_old` for structs only
+///
+/// ### `#[getter(since = "...")]`
+/// Appends "Available since version ..." line with the provided version to the
+/// docs of the derived getter methods. Being used at type level, applies to
+/// all fields, unless they provide their own version.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[getter(default_doc = "...")]`
+/// Appends "Default: `...`." line with the provided value to the docs of the
+/// derived getter methods, documenting the value of the field in
+/// `Default`-constructed type.
+///
+/// **Can be used**: at field level; structs only
+///
+/// ### `#[getter(doc_alias = "...")]`
+/// Adds `#[doc(alias = "...")]` attributes to the derived getter methods, so
+/// rustdoc search finds them under other names as well. Takes comma-separated
+/// list of aliases, like `doc_alias = "coord, position"`. Unlike `alias`, does
+/// not derive any additional methods.
+///
+/// **Can be used**: at field level
+///
+/// ### `#[getter(no_doc)]`
+/// Skips the synthesized "Method returning ..." line in the docs of the derived
+/// methods, leaving only the docs of the field itself (and the version line,
+/// if `since` is used). Being used at type level, applies to all fields.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[getter(stability = "...")]`
+/// Defines stability of the derived getter methods: either `stable` (the
+/// default, producing no warnings) or `experimental`. The first call of an
+/// experimental getter prints a warning to the standard error output (thus,
+/// requires `std`); the following calls are silent. Can't be combined with
+/// `const`.
+///
+/// **Can be used**: at type and field level; structs only
+///
+/// ### `#[getter(trace = "...")]`
+/// Logs each call of the derived methods (including `as_mut` ones) with
+/// `log::trace!("accessing Struct::field")`, which helps debugging access to
+/// large state structs. Without a value (or with an empty one) the argument
+/// requires [`log`](https://docs.rs/log) dependency; otherwise the value
+/// names the feature of the crate using the derive macro which brings it, and
+/// the logging is compiled only if that feature is enabled (the methods are
+/// derived as usual otherwise). Can't be combined with `const`.
 ///
-/// Important, that field-level arguments to override struct-level arguments:
 /// ```
 /// # #[macro_use] extern crate amplify_derive;
-/// #[derive(Getters, Default)]
-/// #[getter(as_copy)]
-/// struct Other {
-///     #[getter(as_ref)]
-///     vec: Vec<u8>,
-///     #[getter(as_clone)]
-///     defaults: String,
-///     pub flag: bool,
-///     pub(self) field: u8,
+/// #[derive(Getters)]
+/// #[getter(trace = "trace", as_copy, as_mut)]
+/// struct State {
+///     hits: u32,
 /// }
 ///
-/// let mut other = Other::default();
-/// assert_eq!(other.vec(), &Vec::<u8>::default());
-/// assert_eq!(other.defaults(), String::from(""));
+/// let mut state = State { hits: 0 };
+/// *state.hits_mut() += 1;
+/// assert_eq!(state.hits(), 1);
 /// ```
 ///
-/// Advanced use: please pay attention that `as_mut` on a struct level is not
-/// removed by the use of `as_copy` at field level.
+/// **Can be used**: at type and field level; structs only
 ///
-/// ```
-/// # #[macro_use] extern crate amplify_derive;
-/// #[derive(Getters, Default)]
-/// #[getter(as_mut, prefix = "get_")]
-/// struct One {
-///     /// Contains byte representation of the data
-///     #[getter(all, base_name = "bytes")]
-///     vec: Vec<u8>,
+/// ### `#[getter(no_tarpaulin = "...")]`
+/// Marks derived methods with `#[cfg_attr(<predicate>, coverage(off))]`,
+/// which excludes them from coverage reports when the provided configuration
+/// predicate holds, and is a no-op otherwise. Unlike
+/// `#[cfg(not(tarpaulin_include))]`, this does not remove the methods, so they
+/// are still available to the code under coverage. Since `coverage(off)` is
+/// unstable, the predicate must hold only for nightly coverage runs, so stable
+/// builds (including tarpaulin ones, which set `tarpaulin` configuration
+/// option) are not affected. The common choice is `coverage_nightly`, which
+/// is set by `cargo llvm-cov` on nightly and can be passed to tarpaulin with
+/// `RUSTFLAGS="--cfg coverage_nightly"`; the crate then enables the attribute
+/// with `#![cfg_attr(coverage_nightly, feature(coverage_attribute))]`. The
+/// configuration options used in the predicate must be declared in the crate
+/// manifest to avoid `unexpected_cfgs` warnings, like
+/// `unexpected_cfgs = { level = "warn", check-cfg = ["cfg(coverage_nightly)"] }`
+/// in `[lints.rust]` section.
 ///
-///     defaults: String,
+/// **Can be used**: at type and field level
 ///
-///     #[getter(as_copy)]
-///     pub flag: bool,
+/// ### `#[getter(ret = "...", expr = "...")]`
+/// Overrides the body of the derived methods with the provided expression; if
+/// `ret` is also given, it replaces the field type in the method signatures
+/// (keeping the reference or mutable reference forms for `as_ref` and
+/// `as_mut`). This allows exposing a different type than the one which is
+/// stored, like `&[u8]` for `Box<[u8]>` field. `ret` can't be used without
+/// `expr`. The same expression is used for all methods derived for the field,
+/// so it is advised to combine these arguments with a single method.
 ///
-///     #[getter(skip)]
-///     pub(self) field: u8,
-/// }
+/// **Can be used**: at field level; structs only
 ///
-/// let mut one = One::default();
-/// assert_eq!(one.get_bytes_ref(), &Vec::<u8>::default());
-/// *one.get_bytes_mut() = vec![0, 1, 2];
-/// assert_eq!(one.get_defaults(), "");
-/// assert_eq!(one.get_defaults_mut(), "");
-/// assert_eq!(one.get_bytes(), vec![0, 1, 2]);
-/// assert_eq!(one.get_flag(), bool::default());
-/// assert_eq!(one.get_flag_mut(), &mut bool::default());
-/// let flag = one.get_flag_mut();
-/// *flag = true;
-/// assert_eq!(one.get_flag(), true);
-/// assert_eq!(one.flag, one.get_flag());
-/// // method does not exist: assert_eq!(one.get_field(), u8::default());
-/// ```
+/// ### `#[getter(cached = "...")]`
+/// Memoizes value computed by `expr`: the expression is evaluated on the first
+/// call only, and its value is stored in the `OnceCell` given by the argument
+/// (like `self.area_cache`), so the getter returns reference to the cached
+/// value. The cell is not derived and must be declared by the user as a field
+/// of `std::cell::OnceCell<T>` (or any other type providing `get_or_init`
+/// method, like `once_cell::unsync::OnceCell<T>`), where `T` is the field type
+/// or the type given with `ret`. Requires `expr` and `as_ref` to be the only
+/// method derived for the field. Keep in mind that the cached value is not
+/// updated when the fields it is computed from change.
 ///
-/// this will end up in the following generated code:
-/// ```
-/// # struct One {
-/// #    vec: Vec<u8>,
-/// #    pub flag: bool,
-/// #    pub(self) field: u8,
-/// # }
+/// **Can be used**: at field level; structs only
 ///
-/// impl One {
-///     #[doc = "Method cloning [`One::vec`] field.\n"]
-///     #[doc = " Contains byte representation of the data"]
-///     #[inline]
-///     pub fn get_bytes(&self) -> Vec<u8> { self.vec.clone() }
+/// ### `#[getter(alias = "...")]` and `#[getter(deprecated_alias)]`
+/// Derives additional methods for the field, which names use the provided
+/// alias instead of the base name, while the rest of the name is composed in
+/// the same way. Useful to keep old method names after a field is renamed.
 ///
-///     #[doc = "Method borrowing [`One::vec`] field.\n"]
-///     #[doc = " Contains byte representation of the data"]
-///     #[inline]
-///     pub fn get_bytes_ref(&self) -> &Vec<u8> { &self.vec }
+/// With `deprecated_alias`, alias methods are marked as deprecated (with a note
+/// pointing to the canonical method) and hidden from the docs; the canonical
+/// methods are not affected.
 ///
-///     #[doc = "Method returning mutable borrow of [`One::vec`] field.\n"]
-///     #[doc = " Contains byte representation of the data"]
-///     #[inline]
-///     pub fn get_bytes_mut(&mut self) -> &mut Vec<u8> { &mut self.vec }
+/// **Can be used**: at field level; structs only
 ///
-///     #[doc = "Method returning copy of [`One::flag`] field.\n"]
-///     #[inline]
-///     pub fn get_flag(&self) -> bool { self.flag }
+/// ### `#[getter(is_empty = "...")]`
+/// Derives method checking whether a field of `Option<Vec<_>>` type is either
+/// `None` or contains an empty vector. The method name is constructed from the
+/// prefix, base name and the provided suffix. Errors on fields of other types.
 ///
-///     #[doc = "Method returning mutable borrow of [`One::flag`] field.\n"]
-///     #[inline]
-///     pub fn get_flag_mut(&mut self) -> &mut bool { &mut self.flag }
-/// }
-/// ```
-#[proc_macro_derive(Getters, attributes(getter))]
-pub fn derive_getters(input: TokenStream) -> TokenStream {
-    let derive_input = parse_macro_input!(input as DeriveInput);
-    getters::derive(derive_input)
-        .unwrap_or_else(|e| e.to_compile_error())
-        .into()
-}
-
-/// Creates rust new type wrapping existing type. Can be used in structures
-/// containing multiple named or unnamed fields; in this case the field you'd
-/// like to wrap should be marked with `#[wrap]` attribute; otherwise the first
-/// field is assumed to be the wrapped one.
+/// **Defaults to**: `_is_empty` suffix
 ///
-/// NB: You have to use `derive(From)` in order foe Wrapper to work properly.
-/// Also, in case of multiple fields, each non-wrapped field type must implement
-/// `Default` trait.
+/// **Can be used**: at field level
 ///
-/// Supports automatic implementation of the following traits:
-/// * `amplify::Wrapper`
-/// * [`AsRef`]
-/// * [`core::borrow::Borrow`]
-/// You may skip `AsRef` and `Borrow` implementations with `#[wrapper(NoRefs)]`.
+/// ### `#[getter(entry = "...")]`
+/// Derives method taking a key and returning mutable borrow of the value under
+/// it in a field of `HashMap<K, V>` type; if the key is not present, a default
+/// value is inserted first. Requires `V: Default`. Maps with custom hasher
+/// (`HashMap<K, V, S>`) are supported as well. The method name is
+/// constructed from the prefix, base name and the provided suffix. Errors on
+/// fields of other types.
 ///
-/// You can implement additional derives, it they are implemented for the
-/// wrapped type, using `#[wrapper()]` proc macro:
-/// 1. Reference access to the inner type:
+/// **Defaults to**: `_entry` suffix
+///
+/// **Can be used**: at field level
+///
+/// ### `#[getter(set_if_changed = "...")]`
+/// Derives method assigning new value to the field only if it differs from the
+/// current one, and returning `bool` telling whether the value was changed.
+/// Requires field type to implement `PartialEq`. The method name is
+/// constructed from the prefix, base name and the provided suffix.
+///
+/// **Defaults to**: `_set_if_changed` suffix
+///
+/// **Can be used**: at field level; structs only
+///
+/// ### `#[getter(observable)]` and `#[getter(on_change = "...")]`
+/// `observable` derives `set_{field}(&mut self, value)` method, which assigns
+/// the value and then notifies about the change by calling the callback given
+/// by the type-level `on_change` argument with the field name, like
+/// `(self.on_change)("field")`. The callback is an expression (usually a field
+/// of `Box<dyn FnMut(&'static str)>` or `fn(&'static str)` type, which should
+/// be skipped), and it is required by `observable`. The setter is derived
+/// together with the getters, so it is not derived for skipped fields; use
+/// `skip_main` and similar arguments to derive it alone.
+///
+/// **Can be used**: `observable` at field level, `on_change` at type level;
+/// structs only
+///
+/// ### `#[getter(flatten = "...", flatten_prefix = "...")]`
+/// Derives methods forwarding to the getters of the field type, which is
+/// useful for the structs composed out of other structs. Since the macro can't
+/// see the inner type, the argument must list each of the forwarded getters
+/// together with their return type, like in `flatten = "port: u16, host:
+/// &str"`. Forwarding methods are named by prefixing the inner getter names
+/// with `flatten_prefix`.
+///
+/// **Defaults to**: `flatten_prefix` is the base name of the field followed by
+/// `_`
+///
+/// **Can be used**: at field level; structs only
+///
+/// ### `#[getter(rename_all = "...")]`
+/// Converts case of all derived method names according to the provided rule,
+/// which may be one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`,
+/// `snake_case` or `SCREAMING_SNAKE_CASE`.
+///
+/// The conversion is applied to the complete method name, i.e. to the name
+/// composed out of the prefix, base name (or field name) and the
+/// method-specific suffix. Thus, `base_name` defines the source for the method
+/// name, and `rename_all` - its final case.
+///
+/// **Defaults to**: none (names are not converted)
+///
+/// **Can be used**: at type level
+///
+/// ### `#[getter(follow_serde_rename)]`
+/// Uses the rule of `#[serde(rename_all = "...")]` attribute of the type as
+/// `rename_all` rule, so the getter names follow the case of serialized field
+/// names (if the rules for serialization and deserialization are different,
+/// the serialization one is used). The serde attribute is only read and remains
+/// intact; it must be present and can't be combined with `rename_all` getter
+/// argument. Rules producing names which are not valid identifiers, like
+/// `kebab-case`, are not supported.
+///
+/// **Can be used**: at type level
+///
+/// ### `#[getter(rename_all_fields = "...")]`
+/// Applies a chain of transformations to all derived method names. The chain
+/// is a `|`-separated list of the following steps, applied in the given order:
+/// - `strip:<prefix>`: removes the prefix from the name, if the name starts
+///   with it (names not starting with the prefix are left unchanged);
+/// - `add:<prefix>`: adds the prefix to the name;
+/// - `case:<style>`: converts name case using one of the rules supported by
+///   `rename_all` argument.
+///
+/// For instance, `rename_all_fields = "strip:raw_|add:get_|case:camelCase"`
+/// produces `getData` method for `raw_data` field. Like `rename_all`,
+/// transformations are applied to the complete method names (after
+/// `rename_all`, if both are present).
+///
+/// **Defaults to**: none (names are not transformed)
+///
+/// **Can be used**: at type level
+///
+/// ### `#[getter(validate)]`, `#[getter(validate_all)]` and `#[getter(validator = "...")]`
+/// Type-level `validate` derives `validate(&self)` method calling field
+/// validators in the order of field declaration and returning the first
+/// failure. Type-level `validate_all` derives
+/// `validate_all(&self) -> Result<(), Vec<String>>` method, which runs all
+/// validators and returns messages of all failed ones. Both arguments may be
+/// used together. Validators are provided with the field-level `validator` argument,
+/// containing a path to a function of `fn(&T) -> Result<(), String>` signature,
+/// where `T` is the field type. Fields without validators are not validated.
+///
+/// Since proc macro crates can't export types, the error type is generated
+/// next to the struct and is named `{Struct}ValidationError`. It has public
+/// `field: &'static str` and `message: String` fields, containing the name of
+/// the failed field (or its index for tuple structs) and the message returned
+/// by its validator.
+///
+/// **Can be used**: `validate` and `validate_all` at type level, `validator` at
+/// field level;
+/// structs only
+///
+/// ### `#[getter(field_enum)]`
+/// Generates `{Struct}Field` enum with a variant for each of the non-skipped
+/// fields, named after the field in `PascalCase` (for tuple structs, after its
+/// `base_name`), and `Display` implementation for it printing the original
+/// field name.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(diff)]`
+/// Derives `changed_fields(&self, other: &Self) -> Vec<&'static str>` method
+/// returning base names (see `base_name` argument) of the getters for all
+/// fields which values differ between `self` and `other`. Skipped fields are
+/// not compared; all other fields must implement `PartialEq`.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(no_getters)]`
+/// Disables derivation of all getter methods (including aliases and other
+/// field-level methods), leaving only the type-level companion items, like the
+/// ones requested with `const_new`, `snapshot`, `diff` or `field_enum`. Can't
+/// be combined with `trait` and `gen_tests`, which require getters.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(merge)]`
+/// Derives `merge(&mut self, other: Self)` method, which assigns values from
+/// `other` to the fields which are `None`, keeping the fields which already
+/// have a value. This is useful for layering configurations, where the values
+/// from the higher-priority layer are merged with the lower-priority one. All
+/// fields with getters must be of `Option` type; skipped fields are not changed
+/// by the merge.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(swap = "...")]`
+/// Derives `swap_{field}_{other}(&mut self)` method exchanging the value of the
+/// field with the value of the other field with the given name (or index, for
+/// tuple structs), which must be of the same type. Useful for the state
+/// machines alternating between two values, like current and previous states.
+/// Raw identifier fields may be named with or without `r#` prefix, which is
+/// omitted in the method name (`swap_type_kind` for `r#type` field).
+///
+/// **Can be used**: at field level; structs only
+///
+/// ### `#[getter(zeroize = "...")]` and `#[getter(zeroize_all = "...")]`
+/// Derives `zeroize(&mut self)` method clearing the values of the fields
+/// marked with `zeroize` (or of all fields, including skipped ones, with
+/// type-level `zeroize_all`) with [`zeroize::Zeroize`](https://docs.rs/zeroize)
+/// trait, which must be implemented for their types. Without a value (or with
+/// an empty one) the argument requires `zeroize` dependency; otherwise the
+/// value names the feature of the crate using the derive which brings it,
+/// and the fields are assigned `Default::default()` values when that feature
+/// is disabled. Field-level `zeroize` overrides the feature of `zeroize_all`.
+///
+/// Keep in mind that the compiler may optimize out the assignment of default
+/// values when they are not read afterwards, so it can't be relied on to
+/// erase secrets from memory. Neither of the approaches clears the copies left
+/// by moving the struct or by reallocating its buffers.
+///
+/// **Can be used**: `zeroize` at field level, `zeroize_all` at type level;
+/// structs only
+///
+/// ### `#[getter(only = "...")]`
+/// Derives getters only for the fields from the provided comma-separated list
+/// of field names (or indexes, for tuple structs), like `only = "name, port"`;
+/// all other fields are treated as skipped, regardless of their own
+/// attributes. Unknown field names result in an error.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(repr_c_assert)]`
+/// Fails the compilation unless the struct is `#[repr(C)]` (possibly combined
+/// with other hints, like `#[repr(C, packed)]`), guarding the layout of the
+/// structs shared over FFI from being changed accidentally. Does not affect
+/// the derived methods.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(into_parts)]`
+/// Derives `into_parts(self) -> (T1, T2, ...)` method consuming the struct and
+/// returning tuple with the values of all non-skipped fields in the order of
+/// their declaration (skipped fields are dropped). Conditionally compiled
+/// fields must be skipped, since tuple elements can't be conditional.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(with_capacity)]`
+/// Derives `with_capacity(capacity: usize) -> Self` constructor, which
+/// preallocates all `Vec`, `VecDeque`, `String`, `HashMap` and `HashSet` fields
+/// (including the ones without getters) to hold at least `capacity` elements,
+/// and initializes other fields with `Default::default()`, requiring their
+/// types to implement `Default`. Collections are detected by the last segment
+/// of the type path, so type aliases are treated as non-collection types.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(freeze)]`
+/// Derives `Frozen{Struct}` type, having the same fields as the struct with
+/// `Vec<T>` fields replaced by `Box<[T]>`, and `freeze(self) ->
+/// Frozen{Struct}` method converting the struct into it. All fields, including
+/// the ones without getters, are moved into the frozen type; its fields get
+/// the visibility of the struct. `Vec` fields are detected by the last segment
+/// of the type path.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(ensure_send_sync)]`
+/// Fails the compilation unless the struct is `Send + Sync`, catching
+/// accidental introduction of fields like `Rc` or `RefCell`. Does not affect
+/// the derived methods. Generic structs are not supported.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(try_into_inner)]`
+/// For newtype-like structs with exactly one non-skipped field derives a
+/// method consuming the struct and returning value of the field (other fields
+/// are dropped). If the struct is validated with `validate`, the method is
+/// `try_into_inner(self) -> Result<Inner, {Struct}ValidationError>`, which
+/// validates the struct first; otherwise it is `into_inner(self) -> Inner`.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(from_parts)]`
+/// Derives `from_parts(parts: (T1, T2, ...)) -> Self` constructor, which is
+/// the reverse of `into_parts`: it takes tuple with the values of all
+/// non-skipped fields in the order of their declaration. Skipped fields are
+/// initialized with `Default::default()`, so their types must implement
+/// `Default`. Conditionally compiled fields must be skipped.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(const_new)]`
+/// Derives `const fn new` constructor taking values for all struct fields in
+/// the order of their declaration. Skipped fields are required as well, since
+/// `const` context doesn't allow to fill them with defaults. Arguments are
+/// named after the fields; for tuple structs, after the field `base_name` (or
+/// `field_{index}` if no base name is given).
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(partial_eq_subset)]`
+/// Derives `eq_public(&self, other: &Self) -> bool` method comparing only the
+/// fields which are not skipped, so two values with different caches or other
+/// internal state are still equal. All non-skipped fields must implement
+/// `PartialEq`.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(as_result = "value, error")]`
+/// Derives `result(&self) -> Result<&T, &E>` method for a struct with
+/// `value: Option<T>` and `error: Option<E>` fields, named in the argument.
+/// The method returns `Ok` if the value field is set (even if the error field
+/// is set as well), `Err` if only the error field is set, and panics if
+/// neither of them is set.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(group = "...")]`
+/// Derives methods borrowing several fields together, returning a tuple of
+/// references, i.e. `fn coords(&self) -> (&X, &Y)` for `group = "coords: x,
+/// y"`. Each group lists method name followed by a colon and at least two
+/// names of the fields; multiple groups are separated with semicolons, like
+/// `group = "coords: x, y; size: width, height"`. Fields are referenced by
+/// their names regardless whether they are skipped by the getters.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(field_names)]`
+/// Derives `FIELDS: &'static [&'static str]` associated constant listing the
+/// getter base names (see `base_name` argument) of all non-skipped fields, in
+/// the order of their declaration. For tuple structs these are the names
+/// given with `base_name` (or `_{index}` with `prefix_numeric_fields`).
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(allow_empty)]`
+/// Derives empty implementation for unit structs, which otherwise are rejected
+/// since they have no fields to derive getters for. Useful for the code
+/// deriving getters uniformly over many types, like other macros; structs with
+/// no fields or with all fields skipped get empty implementation regardless of
+/// the argument. Rest of the type-level arguments are ignored for unit
+/// structs.
+///
+/// **Can be used**: at type level
+///
+/// ### `#[getter(snapshot)]`
+/// Derives `snapshot(&self) -> Vec<(String, String)>` method returning pairs of
+/// getter base names and `Debug` representations of the values for all
+/// non-skipped fields, in the order of their declaration. Useful for logging
+/// and diagnostics; all non-skipped fields must implement `Debug`.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(checksum)]` and `#[getter(checksum_include)]`
+/// Derives `checksum(&self) -> u64` method computing 64-bit FNV-1a hash over the
+/// byte contents of the fields marked with `checksum_include`. The fields are
+/// hashed in the order of their declaration, each prefixed with its length
+/// encoded as a little-endian `u64`, so the checksum is deterministic across
+/// platforms and compiler versions. Included fields must implement
+/// `AsRef<[u8]>`.
+///
+/// **Can be used**: `checksum` at type level, `checksum_include` at field level;
+/// structs only
+///
+/// ### `#[getter(gen_tests)]`
+/// Generates `#[cfg(test)]` module named `{struct_name}_getter_tests` (in
+/// snake case) with a unit test for each of the derived getter methods,
+/// checking that for a `Default`-constructed struct the method returns the
+/// value of the field. Requires the struct to implement `Default` and the
+/// values returned by the getters to implement `PartialEq`. Getters with custom
+/// `expr`, `pin` and `as_into` getters, borrowing getters with `self_ty`
+/// receivers and getters of reference and boxed trait object fields are not
+/// tested. Generic structs are not supported.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(arbitrary_ctor = "...")]`
+/// Derives `from_unstructured(u: &mut arbitrary::Unstructured) ->
+/// arbitrary::Result<Self>` constructor reading values of all fields (including
+/// skipped ones) with the [`arbitrary`](https://docs.rs/arbitrary) crate, which
+/// is useful for fuzzing. All the field types must implement
+/// `arbitrary::Arbitrary`. If the argument is given a feature name, like
+/// `#[getter(arbitrary_ctor = "fuzzing")]`, the constructor is compiled only if
+/// the crate using the derive macro has this feature enabled; the feature must
+/// be declared in the crate manifest (bringing `arbitrary` dependency), so the
+/// crates building without it don't get `unexpected_cfgs` warnings. Without a
+/// feature name the constructor is derived unconditionally and the crate must
+/// depend on `arbitrary`.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(proptest_strategy = "...")]`
+/// Derives `strategy() -> impl proptest::strategy::Strategy<Value = Self>`
+/// function composing [`proptest`](https://docs.rs/proptest) strategies
+/// `any::<T>()` for the values of all fields (including skipped ones), which
+/// is useful for property testing. All the field types must implement
+/// `proptest::arbitrary::Arbitrary`. Like with `arbitrary_ctor`, the function
+/// is compiled only if the crate using the derive macro has the feature given
+/// as the argument value enabled, and is derived unconditionally if no feature
+/// name is given.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(cfg = "...")]`
+/// Compiles all derived items (the impl blocks, as well as the traits, types
+/// and tests derived for the type) only if the provided configuration predicate
+/// holds, like in `#[getter(cfg = "feature = \"accessors\"")]`. Unlike the
+/// `cfg` attributes of the fields, which are forwarded to their getters, this
+/// gates the whole API surface derived by the macro.
+///
+/// **Can be used**: at type level
+///
+/// ### `#[getter(trait = "...")]`
+/// Places derived getter methods into a trait with the provided name instead of
+/// the inherent impl block of the type. The trait is declared with the same
+/// visibility and generic parameters as the type and has a single
+/// implementation for it. Other derived methods (like `validate` or
+/// `changed_fields`) are still placed into the inherent impl block. Can't be
+/// combined with `const`, since trait methods can't be `const`.
+///
+/// **Can be used**: at type level; structs only
+///
+/// ### `#[getter(delegate_deref = "...")]`
+/// For types implementing `Deref`, derives methods delegating to the methods of
+/// the type they dereference to. Since the macro can't know the signatures of
+/// the inner type methods, the argument must list each of the delegated
+/// methods together with their return type, like in
+/// `delegate_deref = "width: u32, name: &str"`. Delegated methods must take
+/// `&self` and no other arguments.
+///
+/// **Can be used**: at type level; structs only
+///
+/// # Enums
+///
+/// For enums, getters are derived for each of the variants, which must have a
+/// single unnamed field (other variants must be marked with `#[getter(skip)]`).
+/// The methods return `Option` with the inner data (borrowed, copied or cloned,
+/// depending on the method), which is `None` if the enum has a different
+/// variant. Method base names are constructed from the variant names converted
+/// into snake case; the default prefix for enums is `as_`.
+///
+/// With `#[getter(try_into)]` (at type or variant level) enums also get
+/// `try_into_{variant}(self) -> Result<T, Self>` methods, moving out the inner
+/// data of the matching variant and returning the value back as an error
+/// otherwise, so the caller can recover it. The method prefix can be changed
+/// with `try_into = "..."`. Not supported in structs.
+///
+/// # Attribute `#[accessor(...)]`
+///
+/// Structs deriving both `Getters` and [`Setters`] may use `#[accessor(...)]`
+/// attribute to provide arguments shared by both derives: `prefix` and `vis`
+/// at type and field level, `skip` and `base_name` at field level only. The
+/// `#[getter(...)]` and `#[setter(...)]` attributes take precedence over the
+/// shared arguments of the same level, while field-level arguments, as usual,
+/// take precedence over the type-level ones.
+///
+/// Shared `vis` sets visibility of all the derived methods, like `vis`
+/// argument of each derive. Shared `prefix` does not replace the prefixes of
+/// the derives, which differ, but is put after the type-level `prefix` of
+/// each derive (or its default: none for struct getters, `as_` for enum
+/// getters and `set_` for setters), so `#[accessor(prefix = "raw_")]` on
+/// `data` field results in `raw_data` getter and `set_raw_data` setter. The
+/// composed prefix is replaced by a field-level `prefix` of a derive, and
+/// replaces the prefix derived with `prefix_from_type`. Prefixes of other
+/// setter methods, like `build` and `replace`, are not affected.
+///
+/// # Errors
+///
+/// Unions are not supported; attempt to derive `Getters` on them will result
+/// in a compile-time error. The same applies to enum variants which are not
+/// skipped and have zero, several or named fields.
+///
+/// Deriving getters on unit structs is not supported (since it's meaningless),
+/// and results in a error. Tuple structs are supported, but each of their
+/// fields must be provided with `base_name` argument; otherwise an error is
+/// produced.
+///
+/// Additionally to these two cases, macro errors on argument inconsistencies,
+/// as described in the argument-specific sections.
+///
+/// # Examples
+///
+/// Basic use:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// struct One {
+///     vec: Vec<u8>,
+///     defaults: String,
+///     #[getter(as_copy)]
+///     pub flag: bool,
+///     #[getter(as_copy)]
+///     pub(self) field: u8,
+/// }
+///
+/// let mut one = One::default();
+/// assert_eq!(one.vec(), &Vec::<u8>::default());
+/// assert_eq!(one.defaults(), "");
+/// assert_eq!(one.flag(), false);
+/// assert_eq!(one.field(), 0);
+/// ```
+///
+/// Important, that field-level arguments to override struct-level arguments,
+/// while the fields without own arguments use the struct-level ones:
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy)]
+/// struct Other {
+///     #[getter(as_ref)]
+///     vec: Vec<u8>,
+///     #[getter(as_clone)]
+///     defaults: String,
+///     pub flag: bool,
+///     pub(self) field: u8,
+/// }
+///
+/// let mut other = Other::default();
+/// let vec: &Vec<u8> = other.vec();
+/// let defaults: String = other.defaults();
+/// let flag: bool = other.flag();
+/// let field: u8 = other.field();
+/// assert_eq!(vec, &Vec::<u8>::default());
+/// assert_eq!(defaults, String::from(""));
+/// assert_eq!((flag, field), (false, 0));
+///
+/// #[derive(Getters, Default)]
+/// #[getter(as_clone)]
+/// struct Cloned {
+///     #[getter(as_copy)]
+///     port: u16,
+///     name: String,
+/// }
+///
+/// let cloned = Cloned::default();
+/// let port: u16 = cloned.port();
+/// let name: String = cloned.name();
+/// assert_eq!((port, name), (0, String::new()));
+/// ```
+///
+/// Converting getters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Clone, PartialEq, Eq, Debug)]
+/// struct UserId(u32);
+///
+/// impl From<u32> for UserId {
+///     fn from(id: u32) -> Self { UserId(id) }
+/// }
+///
+/// #[derive(Getters)]
+/// struct Session<T> {
+///     #[getter(as_copy, as_into = "_as_user", into_type = "UserId")]
+///     user: u32,
+///     #[getter(as_into, into_type = "u64")]
+///     token: T,
+/// }
+///
+/// let session = Session { user: 7, token: 5u32 };
+/// assert_eq!(session.user(), 7);
+/// assert_eq!(session.user_as_user(), UserId(7));
+/// assert_eq!(session.token_into(), 5u64);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Session {
+///     #[getter(as_into)]
+///     user: u32,
+/// }
+/// ```
+///
+/// Owned copies of borrowed data:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Request<'a> {
+///     #[getter(to_owned)]
+///     path: &'a str,
+///     #[getter(as_ref, to_owned = "_vec")]
+///     body: &'a [u8],
+/// }
+///
+/// let request = Request { path: "/index", body: b"data" };
+/// let path: String = request.path_owned();
+/// assert_eq!(path, "/index");
+/// assert_eq!(request.body_vec(), b"data".to_vec());
+/// ```
+///
+/// By-value getters with explicit bounds:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(main)]
+/// struct Account {
+///     name: String,
+///     balance: u64,
+///     #[getter(as_ref)]
+///     history: Vec<u64>,
+/// }
+///
+/// let account = Account::default();
+/// assert_eq!(account.name(), String::new());
+/// assert_eq!(account.balance(), 0);
+/// assert_eq!(account.history(), &Vec::<u64>::new());
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// struct Handle;
+///
+/// #[derive(Getters)]
+/// #[getter(main)]
+/// struct Account {
+///     handle: Handle,
+/// }
+/// ```
+///
+/// Constant getters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_copy, const)]
+/// struct Limits {
+///     max_size: usize,
+///     max_depth: u8,
+/// }
+///
+/// const LIMITS: Limits = Limits {
+///     max_size: 1024,
+///     max_depth: 8,
+/// };
+/// const BUFFER: [u8; LIMITS.max_size()] = [0; LIMITS.max_size()];
+/// assert_eq!(BUFFER.len(), 1024);
+/// assert_eq!(LIMITS.max_depth(), 8);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_clone, const)]
+/// struct Limits {
+///     name: String,
+/// }
+/// ```
+///
+/// Explicit lifetimes in the derived method signatures:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::borrow::Cow;
+///
+/// #[derive(Getters)]
+/// #[getter(explicit_generics, as_ref, as_mut)]
+/// struct Pair<'a, K, V>
+/// where
+///     K: ?Sized + ToOwned,
+/// {
+///     key: Cow<'a, K>,
+///     value: V,
+///     #[getter(as_deref)]
+///     label: String,
+/// }
+///
+/// fn key_of<'p, 'a, V>(pair: &'p Pair<'a, str, V>) -> &'p Cow<'a, str> { pair.key() }
+///
+/// let mut pair = Pair {
+///     key: Cow::Borrowed("key"),
+///     value: vec![1u8],
+///     label: String::from("label"),
+/// };
+/// pair.value_mut().push(2);
+/// assert_eq!(key_of(&pair), "key");
+/// assert_eq!(pair.value(), &[1, 2]);
+/// assert_eq!(pair.label_deref(), "label");
+/// ```
+///
+/// Consuming getters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_mut, into)]
+/// struct Builder {
+///     parts: Vec<String>,
+/// }
+///
+/// let mut builder = Builder::default();
+/// builder.parts_mut().push(String::from("first"));
+/// assert_eq!(builder.parts(), &vec![String::from("first")]);
+/// assert_eq!(builder.into_parts(), vec![String::from("first")]);
+///
+/// #[derive(Getters)]
+/// #[getter(into = "unwrap_")]
+/// enum Value {
+///     Text(String),
+///     Number(u64),
+/// }
+///
+/// assert_eq!(Value::Text(String::from("text")).unwrap_text(), Some(String::from("text")));
+/// assert_eq!(Value::Number(5).unwrap_text(), None);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Pair {
+///     #[getter(into)]
+///     first: String,
+///     second: String,
+/// }
+/// ```
+///
+/// Tuple structs require `base_name` on each of the fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// struct Newtype(#[getter(base_name = "value")] Vec<u8>);
+///
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy)]
+/// struct Pair(#[getter(base_name = "first")] u8, #[getter(base_name = "second")] u16);
+///
+/// let newtype = Newtype(vec![1, 2, 3]);
+/// assert_eq!(newtype.value(), &vec![1, 2, 3]);
+///
+/// let pair = Pair(1, 2);
+/// assert_eq!(pair.first(), 1);
+/// assert_eq!(pair.second(), 2);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Pair(#[getter(base_name = "first")] u8, u16);
+/// ```
+///
+/// Naming tuple struct getters after the field indexes:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_copy, prefix_numeric_fields)]
+/// struct Triple(u8, u16, #[getter(base_name = "last")] u32);
+///
+/// let triple = Triple(1, 2, 3);
+/// assert_eq!(triple._0(), 1);
+/// assert_eq!(triple._1(), 2);
+/// assert_eq!(triple.last(), 3);
+///
+/// #[derive(Getters)]
+/// #[getter(prefix = "get", prefix_numeric_fields)]
+/// struct Pair(String, Vec<u8>);
+///
+/// let pair = Pair(String::from("key"), vec![1]);
+/// assert_eq!(pair.get_0(), "key");
+/// assert_eq!(pair.get_1(), &vec![1]);
+/// ```
+///
+/// Custom method names:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(prefix = "get_", as_ref, as_mut)]
+/// struct Point {
+///     #[getter(rename = "coordinate")]
+///     x0: i32,
+///     y: i32,
+/// }
+///
+/// let mut point = Point::default();
+/// *point.coordinate_mut() = 5;
+/// assert_eq!(point.coordinate(), &5);
+/// assert_eq!(point.get_y(), &0);
+///
+/// #[derive(Getters)]
+/// struct Newtype(#[getter(rename = "value")] Vec<u8>);
+/// assert_eq!(Newtype(vec![1]).value(), &[1]);
+///
+/// #[derive(Getters, Default)]
+/// #[getter(prefix = "get_", suffix = "_val", as_ref, as_mut)]
+/// struct Sample {
+///     field: u8,
+///     #[getter(suffix = "")]
+///     other: u8,
+/// }
+///
+/// let mut sample = Sample::default();
+/// *sample.get_field_val_mut() = 1;
+/// assert_eq!(sample.get_field_val(), &1);
+/// assert_eq!(sample.get_other(), &0);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(suffix = "-val")]
+/// struct Sample {
+///     field: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(prefix = "2get")]
+/// struct Sample {
+///     field: u8,
+/// }
+/// ```
+///
+/// Prefixing methods with the names of the field types:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Default)]
+/// struct Config;
+/// #[derive(Default)]
+/// struct HttpClient;
+///
+/// #[derive(Getters, Default)]
+/// #[getter(prefix_from_type)]
+/// struct Node {
+///     inner: Config,
+///     outer: HttpClient,
+///     #[getter(prefix = "")]
+///     id: u32,
+/// }
+///
+/// let node = Node::default();
+/// let _: &Config = node.config_inner();
+/// let _: &HttpClient = node.http_client_outer();
+/// assert_eq!(node.id(), &0);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Node {
+///     #[getter(prefix_from_type)]
+///     peer: (u8, u16),
+/// }
+/// ```
+///
+/// String arguments given a value of other kind, like an integer or a path,
+/// or given without a value are reported naming the expected value:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(prefix = 5)]
+/// struct Sample {
+///     field: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(prefix = get)]
+/// struct Sample {
+///     field: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Sample {
+///     #[getter(base_name)]
+///     field: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Point {
+///     #[getter(rename = "x-coordinate")]
+///     x0: i32,
+/// }
+/// ```
+///
+/// Fields with raw identifiers get getters named without `r#` prefix, which is
+/// kept only if the composed name is a keyword:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_ref, as_mut)]
+/// struct Token {
+///     r#type: u8,
+///     r#match: String,
+/// }
+///
+/// let mut token = Token::default();
+/// *token.type_mut() = 1;
+/// token.match_mut().push('a');
+/// assert_eq!(token.r#type(), &1);
+/// assert_eq!(token.r#match(), "a");
+/// ```
+///
+/// Copies and clones of `Result` and `Option` fields must be used:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #![deny(unused_must_use)]
+///
+/// #[derive(Getters)]
+/// #[getter(as_clone)]
+/// struct Response {
+///     header: Option<String>,
+/// }
+///
+/// let response = Response { header: None };
+/// response.header();
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #![deny(unused_must_use)]
+///
+/// #[derive(Getters)]
+/// #[getter(as_copy)]
+/// struct Response {
+///     status: Result<u16, ()>,
+/// }
+///
+/// let response = Response { status: Ok(200) };
+/// response.status();
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #![deny(unused_must_use)]
+///
+/// #[derive(Getters)]
+/// #[getter(as_clone, must_use)]
+/// struct Document {
+///     body: String,
+/// }
+///
+/// let document = Document { body: String::new() };
+/// document.body();
+/// ```
+///
+/// Naming precedence:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_clone, as_ref = "_ref", rename_all = "snake_case")]
+/// struct Config {
+///     #[getter(base_name = "myField")]
+///     field: String,
+///     #[getter(base_name = "HTTPPort")]
+///     port: u16,
+///     otherField: u8,
+/// }
+///
+/// let config = Config::default();
+/// assert_eq!(config.my_field_ref(), "");
+/// assert_eq!(config.my_field(), "");
+/// assert_eq!(config.http_port_ref(), &0);
+/// assert_eq!(config.other_field(), 0);
+/// ```
+///
+/// Following serde naming convention requires serde `rename_all` attribute
+/// (see `test/serde` crate for the examples):
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(follow_serde_rename)]
+/// struct Config {
+///     max_peers: u16,
+/// }
+/// ```
+///
+/// Borrowing optional values:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_ref, as_mut, option)]
+/// struct Node {
+///     parent: Option<String>,
+///     next: std::option::Option<u32>,
+///     depth: u8,
+/// }
+///
+/// let mut node = Node::default();
+/// assert_eq!(node.parent(), None);
+/// node.parent = Some(String::from("root"));
+/// assert_eq!(node.parent().map(String::as_str), Some("root"));
+/// node.next = Some(1);
+/// *node.next_mut().unwrap() += 1;
+/// assert_eq!(node.next(), Some(&2));
+/// assert_eq!(node.depth(), &0);
+/// ```
+///
+/// Documenting API versions:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(since = "1.0.0")]
+/// pub struct Settings {
+///     /// Number of worker threads
+///     threads: u8,
+///     /// Connection timeout, in seconds
+///     #[getter(since = "1.2.0")]
+///     timeout: u32,
+/// }
+///
+/// let settings = Settings::default();
+/// assert_eq!(settings.threads(), &0);
+/// assert_eq!(settings.timeout(), &0);
+/// ```
+///
+/// Documenting default values:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// pub struct Config {
+///     /// Maximal number of retries
+///     #[getter(default_doc = "3")]
+///     retries: u8,
+/// }
+///
+/// impl Default for Config {
+///     fn default() -> Self { Config { retries: 3 } }
+/// }
+///
+/// assert_eq!(Config::default().retries(), &3);
+/// ```
+///
+/// Making getters discoverable under other names in the docs:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy)]
+/// pub struct Point {
+///     #[getter(doc_alias = "coord, position")]
+///     location: (i32, i32),
+///     #[getter(doc_alias = "z")]
+///     depth: i32,
+/// }
+///
+/// assert_eq!(Point::default().location(), (0, 0));
+/// assert_eq!(Point::default().depth(), 0);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Point {
+///     #[getter(doc_alias = "coord,")]
+///     location: (i32, i32),
+/// }
+/// ```
+///
+/// Getters for conditionally compiled fields are conditionally compiled as
+/// well:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_ref, as_mut)]
+/// struct Config {
+///     name: String,
+///     #[cfg(any())]
+///     #[cfg_attr(any(), allow(dead_code))]
+///     disabled: u8,
+///     #[cfg(all())]
+///     #[cfg_attr(all(), deprecated(note = "will be removed"))]
+///     enabled: u8,
+/// }
+///
+/// let mut config = Config::default();
+/// #[allow(deprecated)]
+/// {
+///     *config.enabled_mut() = 1;
+///     assert_eq!(config.enabled(), &1);
+/// }
+/// ```
+///
+/// Getters of deprecated fields are deprecated as well (made an error here
+/// with `deny(deprecated)`):
+///
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// struct Config {
+///     #[deprecated(since = "1.2.0", note = "use `timeout` instead")]
+///     delay: u32,
+///     timeout: u32,
+/// }
+///
+/// Config::default().delay();
+/// ```
+///
+/// ```
+/// #![deny(deprecated)]
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// struct Config {
+///     #[deprecated(since = "1.2.0", note = "use `timeout` instead")]
+///     delay: u32,
+///     timeout: u32,
+/// }
+///
+/// let config = Config::default();
+/// assert_eq!(config.timeout(), &0);
+/// #[allow(deprecated)]
+/// let delay = config.delay();
+/// assert_eq!(delay, &0);
+/// ```
+///
+/// Deprecations applied with `cfg_attr` are conditional for the getters as
+/// well (`legacy` feature is not enabled here):
+///
+/// ```
+/// #![deny(deprecated)]
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// struct Config {
+///     #[cfg_attr(feature = "legacy", deprecated(note = "use `hops` instead"))]
+///     #[getter(alias = "wait")]
+///     delay: u32,
+///     #[cfg_attr(not(feature = "legacy"), deprecated(note = "use `delay` instead"))]
+///     #[getter(is_empty)]
+///     hops: Option<Vec<u8>>,
+/// }
+///
+/// let config = Config::default();
+/// assert_eq!(config.delay(), &0);
+/// assert_eq!(config.wait(), &0);
+/// #[allow(deprecated)]
+/// let empty = config.hops_is_empty();
+/// assert!(empty);
+/// ```
+///
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// struct Config {
+///     #[cfg_attr(not(feature = "legacy"), deprecated(note = "use `delay` instead"))]
+///     #[getter(is_empty)]
+///     hops: Option<Vec<u8>>,
+/// }
+///
+/// Config::default().hops_is_empty();
+/// ```
+///
+/// Forwarding field docs without the synthesized ones:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(no_doc)]
+/// pub struct Point {
+///     /// Horizontal coordinate.
+///     ///
+///     /// All lines of the field docs are forwarded to the getter docs.
+///     x: i32,
+///     /// Vertical coordinate
+///     y: i32,
+///     #[getter(alias = "altitude")]
+///     z: i32,
+/// }
+///
+/// let point = Point::default();
+/// assert_eq!(point.x(), &0);
+/// assert_eq!(point.y(), &0);
+/// assert_eq!(point.altitude(), &0);
+/// ```
+///
+/// Excluding getters from coverage:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(no_tarpaulin = "coverage_nightly", diff)]
+/// struct Metrics {
+///     hits: u64,
+///     #[getter(as_copy)]
+///     misses: u64,
+/// }
+///
+/// let metrics = Metrics::default();
+/// assert_eq!(metrics.hits(), &0);
+/// assert_eq!(metrics.misses(), 0);
+/// assert!(metrics.changed_fields(&Metrics::default()).is_empty());
+/// ```
+///
+/// Exposing a different type:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Packet {
+///     #[getter(as_ref, ret = "[u8]", expr = "self.payload.as_ref()")]
+///     payload: Box<[u8]>,
+///     #[getter(as_copy, base_name = "hop_count", ret = "usize", expr = "self.hops.len()")]
+///     hops: Vec<u32>,
+/// }
+///
+/// let packet = Packet {
+///     payload: Box::new([1, 2, 3]),
+///     hops: vec![7, 8],
+/// };
+/// let payload: &[u8] = packet.payload();
+/// assert_eq!(payload, &[1, 2, 3]);
+/// assert_eq!(packet.hop_count(), 2);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Packet {
+///     #[getter(ret = "[u8]")]
+///     payload: Box<[u8]>,
+/// }
+/// ```
+///
+/// Memoizing computed values:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::cell::{Cell, OnceCell};
+///
+/// #[derive(Getters)]
+/// #[getter(as_copy)]
+/// struct Rect {
+///     width: u32,
+///     height: u32,
+///     #[getter(
+///         as_ref,
+///         base_name = "area",
+///         ret = "u32",
+///         expr = "self.compute_area()",
+///         cached = "self.area_cache"
+///     )]
+///     area_cache: OnceCell<u32>,
+///     #[getter(skip)]
+///     computations: Cell<u32>,
+/// }
+///
+/// impl Rect {
+///     fn compute_area(&self) -> u32 {
+///         self.computations.set(self.computations.get() + 1);
+///         self.width * self.height
+///     }
+/// }
+///
+/// let rect = Rect {
+///     width: 3,
+///     height: 4,
+///     area_cache: OnceCell::new(),
+///     computations: Cell::new(0),
+/// };
+/// assert_eq!(rect.area(), &12);
+/// assert_eq!(rect.area(), &12);
+/// assert_eq!(rect.computations.get(), 1);
+/// assert_eq!(rect.width() * rect.height(), 12);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// # use std::cell::OnceCell;
+/// #[derive(Getters)]
+/// struct Rect {
+///     #[getter(as_copy, ret = "u32", expr = "3", cached = "self.area")]
+///     area: OnceCell<u32>,
+/// }
+/// ```
+///
+/// Dereferencing getters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// struct Person<T> {
+///     #[getter(as_deref = "_str")]
+///     name: String,
+///     #[getter(as_deref)]
+///     aliases: Vec<String>,
+///     #[getter(as_deref = "_inner")]
+///     data: Box<T>,
+/// }
+///
+/// let person = Person {
+///     name: String::from("Alice"),
+///     aliases: vec![String::from("Al")],
+///     data: Box::new(5u8),
+/// };
+/// let name: &str = person.name_str();
+/// assert_eq!(name, "Alice");
+/// assert_eq!(person.aliases_deref(), &[String::from("Al")][..]);
+/// assert_eq!(person.data_inner(), &5);
+/// assert_eq!(person.name(), &String::from("Alice"));
+/// ```
+///
+/// String slices of owned, boxed and shared strings:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// # use std::rc::Rc;
+/// # use std::sync::Arc;
+/// #[derive(Getters)]
+/// #[getter(as_str)]
+/// struct Names {
+///     owned: String,
+///     boxed: Box<str>,
+///     shared: Rc<str>,
+///     #[getter(as_str = "_name")]
+///     interned: Arc<str>,
+/// }
+///
+/// let names = Names {
+///     owned: String::from("owned"),
+///     boxed: Box::from("boxed"),
+///     shared: Rc::from("shared"),
+///     interned: Arc::from("interned"),
+/// };
+/// assert_eq!(names.owned_str(), "owned");
+/// assert_eq!(names.boxed_str(), "boxed");
+/// assert_eq!(names.shared_str(), "shared");
+/// assert_eq!(names.interned_name(), "interned");
+/// assert_eq!(names.owned(), &String::from("owned"));
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Names {
+///     #[getter(as_str)]
+///     list: Vec<String>,
+/// }
+/// ```
+///
+/// Slices of vectors and arrays:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_slice)]
+/// struct Block<T> {
+///     hash: [u8; 4],
+///     txs: Vec<T>,
+///     #[getter(as_slice = "_bytes")]
+///     payload: Vec<u8>,
+/// }
+///
+/// let block = Block {
+///     hash: [1, 2, 3, 4],
+///     txs: vec![String::from("tx")],
+///     payload: vec![5],
+/// };
+/// let hash: &[u8] = block.hash_slice();
+/// assert_eq!(hash, &[1, 2, 3, 4]);
+/// assert_eq!(block.txs_slice(), &[String::from("tx")]);
+/// assert_eq!(block.payload_bytes(), &[5]);
+/// assert_eq!(block.hash(), &[1, 2, 3, 4]);
+///
+/// #[derive(Getters)]
+/// #[getter(as_slice)]
+/// enum Data {
+///     List(Vec<u16>),
+///     Fixed([u16; 2]),
+/// }
+///
+/// assert_eq!(Data::Fixed([1, 2]).as_fixed_slice(), Some(&[1u16, 2][..]));
+/// assert_eq!(Data::Fixed([1, 2]).as_list_slice(), None);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Names {
+///     #[getter(as_slice)]
+///     names: String,
+/// }
+/// ```
+///
+/// Transforming method names:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_ref, as_mut, rename_all_fields = "strip:raw_|case:camelCase")]
+/// struct Frame {
+///     raw_header_bytes: Vec<u8>,
+///     payload: Vec<u8>,
+/// }
+///
+/// let mut frame = Frame::default();
+/// frame.headerBytesMut().push(1);
+/// assert_eq!(frame.headerBytes(), &vec![1]);
+/// assert_eq!(frame.payload(), &Vec::<u8>::new());
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(rename_all_fields = "strip:raw_|case:kebab-case")]
+/// struct Frame {
+///     raw_header: Vec<u8>,
+/// }
+/// ```
+///
+/// Checking optional vectors for emptiness:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Request {
+///     #[getter(is_empty)]
+///     headers: Option<Vec<String>>,
+/// }
+///
+/// assert!(Request { headers: None }.headers_is_empty());
+/// assert!(Request { headers: Some(vec![]) }.headers_is_empty());
+/// assert!(!Request { headers: Some(vec![String::from("Host")]) }.headers_is_empty());
+/// ```
+///
+/// Accessing map entries:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// # use std::collections::HashMap;
+/// #[derive(Getters, Default)]
+/// struct Registry {
+///     #[getter(entry)]
+///     counters: HashMap<String, u32>,
+///     #[getter(entry = "_list")]
+///     tags: HashMap<u8, Vec<String>>,
+/// }
+///
+/// let mut registry = Registry::default();
+/// *registry.counters_entry(String::from("hits")) += 1;
+/// *registry.counters_entry(String::from("hits")) += 1;
+/// registry.tags_list(1).push(String::from("new"));
+/// assert_eq!(registry.counters()["hits"], 2);
+/// assert_eq!(registry.tags()[&1], vec![String::from("new")]);
+/// ```
+///
+/// Container types are detected with nested generic arguments and lifetimes:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// # use std::borrow::Cow;
+/// # use std::collections::HashMap;
+/// #[derive(Getters, Default)]
+/// #[getter(as_ref, option)]
+/// struct Index<'a> {
+///     #[getter(entry)]
+///     blobs: HashMap<String, Vec<u8>>,
+///     #[getter(entry)]
+///     nested: std::collections::HashMap<(u8, u16), HashMap<Cow<'a, str>, Option<Vec<u8>>>>,
+///     #[getter(is_empty)]
+///     chunks: Option<Vec<Vec<u8>>>,
+///     label: Option<Cow<'a, str>>,
+///     status: Option<Result<Vec<u8>, HashMap<String, String>>>,
+/// }
+///
+/// let mut index = Index::default();
+/// index.blobs_entry(String::from("a")).push(1);
+/// assert_eq!(index.blobs()["a"], vec![1]);
+/// index
+///     .nested_entry((1, 2))
+///     .insert(Cow::Borrowed("key"), Some(vec![3]));
+/// assert_eq!(index.nested()[&(1, 2)]["key"], Some(vec![3]));
+/// assert!(index.chunks_is_empty());
+/// index.label = Some(Cow::Borrowed("label"));
+/// assert_eq!(index.label().map(Cow::as_ref), Some("label"));
+/// assert_eq!(index.status(), None);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// # use std::collections::HashMap;
+/// #[derive(Getters)]
+/// struct Index {
+///     #[getter(is_empty)]
+///     chunks: Option<HashMap<u8, Vec<u8>>>,
+/// }
+/// ```
+///
+/// Tracking changes:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// struct Widget {
+///     #[getter(set_if_changed)]
+///     label: String,
+///     #[getter(set_if_changed = "_update")]
+///     width: u16,
+/// }
+///
+/// let mut widget = Widget::default();
+/// assert!(widget.label_set_if_changed(String::from("OK")));
+/// assert!(!widget.label_set_if_changed(String::from("OK")));
+/// assert_eq!(widget.label(), "OK");
+/// assert!(!widget.width_update(0));
+/// assert!(widget.width_update(80));
+/// assert_eq!(widget.width(), &80);
+/// ```
+///
+/// Forwarding getters of the nested structs:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy)]
+/// struct Limits {
+///     max_peers: u16,
+///     #[getter(as_clone)]
+///     banner: String,
+/// }
+///
+/// #[derive(Getters, Default)]
+/// struct Server {
+///     #[getter(flatten = "max_peers: u16, banner: String")]
+///     limits: Limits,
+///     #[getter(flatten = "max_peers: u16", flatten_prefix = "client_")]
+///     client_limits: Limits,
+/// }
+///
+/// let server = Server::default();
+/// assert_eq!(server.limits_max_peers(), 0);
+/// assert_eq!(server.limits_banner(), "");
+/// assert_eq!(server.client_max_peers(), 0);
+/// ```
+///
+/// Maps with custom hashers and generic parameters are supported as well:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// # use std::collections::hash_map::RandomState;
+/// # use std::collections::HashMap;
+/// # use std::hash::Hash;
+/// #[derive(Getters, Default)]
+/// struct Cache<K: Hash + Eq, S = RandomState> {
+///     #[getter(entry)]
+///     hits: HashMap<String, u32, RandomState>,
+///     #[getter(entry)]
+///     values: HashMap<K, Vec<u8>, S>,
+/// }
+///
+/// let mut cache = Cache::<u16>::default();
+/// *cache.hits_entry(String::from("a")) += 1;
+/// cache.values_entry(1).push(2);
+/// assert_eq!(cache.hits()["a"], 1);
+/// assert_eq!(cache.values()[&1], vec![2]);
+/// ```
+///
+/// Validating fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(validate)]
+/// struct Config {
+///     #[getter(validator = "Config::check_name")]
+///     name: String,
+///     #[getter(validator = "Config::check_port")]
+///     port: u16,
+///     comment: String,
+/// }
+///
+/// impl Config {
+///     fn check_name(name: &String) -> Result<(), String> {
+///         if name.is_empty() {
+///             return Err(String::from("name must not be empty"));
+///         }
+///         Ok(())
+///     }
+///
+///     fn check_port(port: &u16) -> Result<(), String> {
+///         if *port < 1024 {
+///             return Err(format!("port {} is reserved", port));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut config = Config {
+///     name: String::from("node"),
+///     port: 8080,
+///     comment: String::new(),
+/// };
+/// assert_eq!(config.validate(), Ok(()));
+///
+/// config.port = 80;
+/// config.name = String::new();
+/// let err = config.validate().unwrap_err();
+/// assert_eq!(err, ConfigValidationError {
+///     field: "name",
+///     message: String::from("name must not be empty"),
+/// });
+/// config.name = String::from("node");
+/// assert_eq!(config.validate().unwrap_err().field, "port");
+/// assert_eq!(
+///     config.validate().unwrap_err().to_string(),
+///     "invalid value of `port` field: port 80 is reserved"
+/// );
+/// ```
+///
+/// Collecting all validation errors:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(validate_all)]
+/// struct Form {
+///     #[getter(validator = "Self::check_email")]
+///     email: String,
+///     #[getter(validator = "Self::check_age")]
+///     age: u8,
+/// }
+///
+/// impl Form {
+///     fn check_email(email: &String) -> Result<(), String> {
+///         if !email.contains('@') {
+///             return Err(format!("invalid email `{}`", email));
+///         }
+///         Ok(())
+///     }
+///
+///     fn check_age(age: &u8) -> Result<(), String> {
+///         if *age < 18 {
+///             return Err(String::from("age must be at least 18"));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut form = Form {
+///     email: String::from("alice@example.com"),
+///     age: 16,
+/// };
+/// assert_eq!(form.validate_all(), Err(vec![String::from("age must be at least 18")]));
+/// form.email = String::from("alice");
+/// assert_eq!(form.validate_all().unwrap_err().len(), 2);
+/// form.email = String::from("alice@example.com");
+/// form.age = 18;
+/// assert_eq!(form.validate_all(), Ok(()));
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Config {
+///     #[getter(validator = "check_name")]
+///     name: String,
+/// }
+/// # fn check_name(_: &String) -> Result<(), String> { Ok(()) }
+/// ```
+///
+/// Keeping old method names with aliases:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #![deny(deprecated)]
+///
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy)]
+/// struct Server {
+///     #[getter(alias = "listen_port")]
+///     port: u16,
+///     #[getter(alias = "host_name", deprecated_alias)]
+///     host: &'static str,
+/// }
+///
+/// let server = Server::default();
+/// assert_eq!(server.listen_port(), server.port());
+/// assert_eq!(server.host(), "");
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #![deny(deprecated)]
+///
+/// #[derive(Getters, Default)]
+/// struct Server {
+///     #[getter(alias = "host_name", deprecated_alias)]
+///     host: String,
+/// }
+///
+/// Server::default().host_name();
+/// ```
+///
+/// Renaming methods while keeping the old names as deprecated shims:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_mut)]
+/// struct Server {
+///     #[getter(rename = "address", keep_old)]
+///     host: String,
+/// }
+///
+/// let mut server = Server::default();
+/// server.address_mut().push_str("localhost");
+/// assert_eq!(server.address(), "localhost");
+/// #[allow(deprecated)]
+/// {
+///     server.host_mut().push_str(":80");
+///     assert_eq!(server.host(), "localhost:80");
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #![deny(deprecated)]
+///
+/// #[derive(Getters, Default)]
+/// struct Server {
+///     #[getter(rename = "address", keep_old)]
+///     host: String,
+/// }
+///
+/// Server::default().host();
+/// ```
+///
+/// Enumerating fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(field_enum)]
+/// struct Rect {
+///     width: u32,
+///     height: u32,
+///     #[getter(skip)]
+///     cached_area: u64,
+/// }
+///
+/// fn describe(field: RectField) -> &'static str {
+///     match field {
+///         RectField::Width => "horizontal",
+///         RectField::Height => "vertical",
+///     }
+/// }
+///
+/// assert_eq!(describe(RectField::Width), "horizontal");
+/// assert_eq!(RectField::Height.to_string(), "height");
+///
+/// #[derive(Getters)]
+/// #[getter(field_enum)]
+/// struct Range(#[getter(base_name = "start")] u8, #[getter(base_name = "end")] u8);
+/// assert_eq!(format!("{}..{}", RangeField::Start, RangeField::End), "start..end");
+/// ```
+///
+/// Getters on generic structs get the required bounds automatically:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Labeled<T, L> {
+///     #[getter(as_copy)]
+///     value: T,
+///     #[getter(as_clone)]
+///     labels: Vec<L>,
+/// }
+///
+/// let labeled = Labeled {
+///     value: 5u8,
+///     labels: vec!["five"],
+/// };
+/// assert_eq!(labeled.value(), 5);
+/// assert_eq!(labeled.labels(), vec!["five"]);
+///
+/// // The struct itself doesn't require the bounds
+/// struct NotClone;
+/// let _ = Labeled {
+///     value: NotClone,
+///     labels: Vec::<NotClone>::new(),
+/// };
+///
+/// // Neither do the getters of other fields
+/// #[derive(Getters)]
+/// struct Tagged<T> {
+///     #[getter(as_copy)]
+///     value: T,
+///     #[getter(as_ref)]
+///     tag: String,
+/// }
+///
+/// let tagged = Tagged {
+///     value: NotClone,
+///     tag: String::from("none"),
+/// };
+/// assert_eq!(tagged.tag(), "none");
+/// ```
+///
+/// Layering configurations:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy, merge)]
+/// struct Layer {
+///     port: Option<u16>,
+///     #[getter(as_clone)]
+///     host: Option<String>,
+///     verbose: Option<bool>,
+///     #[getter(skip)]
+///     source: &'static str,
+/// }
+///
+/// let mut cli = Layer {
+///     port: Some(8080),
+///     source: "cli",
+///     ..Layer::default()
+/// };
+/// let file = Layer {
+///     port: Some(80),
+///     host: Some(String::from("localhost")),
+///     verbose: None,
+///     source: "file",
+/// };
+/// cli.merge(file);
+/// assert_eq!(cli.port(), Some(8080));
+/// assert_eq!(cli.host().as_deref(), Some("localhost"));
+/// assert_eq!(cli.verbose(), None);
+/// assert_eq!(cli.source, "cli");
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(merge)]
+/// struct Layer {
+///     port: Option<u16>,
+///     host: String,
+/// }
+/// ```
+///
+/// Clearing sensitive data (`zeroize` feature is not enabled here, so the
+/// fields get default values):
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Credentials {
+///     login: String,
+///     #[getter(zeroize = "zeroize", skip)]
+///     password: String,
+///     #[getter(zeroize = "zeroize")]
+///     token: Option<Vec<u8>>,
+/// }
+///
+/// let mut credentials = Credentials {
+///     login: String::from("admin"),
+///     password: String::from("secret"),
+///     token: Some(vec![1, 2, 3]),
+/// };
+/// credentials.zeroize();
+/// assert_eq!(credentials.login(), "admin");
+/// assert!(credentials.password.is_empty());
+/// assert_eq!(credentials.token(), &None);
+/// ```
+///
+/// Swapping values of the fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_copy)]
+/// struct Buffers {
+///     #[getter(swap = "back")]
+///     front: u8,
+///     back: u8,
+/// }
+///
+/// #[derive(Getters)]
+/// #[getter(as_copy)]
+/// struct Pair(#[getter(base_name = "first", swap = "1")] u8, #[getter(base_name = "second")] u8);
+///
+/// let mut buffers = Buffers { front: 1, back: 2 };
+/// buffers.swap_front_back();
+/// assert_eq!((buffers.front(), buffers.back()), (2, 1));
+///
+/// let mut pair = Pair(1, 2);
+/// pair.swap_0_1();
+/// assert_eq!((pair.first(), pair.second()), (2, 1));
+///
+/// #[derive(Getters)]
+/// #[getter(as_copy)]
+/// struct Token {
+///     #[getter(swap = "r#ref")]
+///     r#type: u8,
+///     #[getter(swap = "kind")]
+///     r#ref: u8,
+///     kind: u8,
+/// }
+///
+/// let mut token = Token { r#type: 1, r#ref: 2, kind: 3 };
+/// token.swap_type_ref();
+/// token.swap_ref_kind();
+/// assert_eq!((token.r#type(), token.r#ref(), token.kind()), (2, 3, 1));
+/// ```
+///
+/// Swapped fields must have the same type:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Buffers {
+///     #[getter(swap = "back")]
+///     front: u8,
+///     back: u16,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Buffers {
+///     #[getter(swap = "rear")]
+///     front: u8,
+///     back: u8,
+/// }
+/// ```
+///
+/// Notifying about the changes:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// #[derive(Getters)]
+/// #[getter(as_copy, on_change = "self.on_change")]
+/// struct Model {
+///     #[getter(observable)]
+///     count: u32,
+///     #[getter(observable)]
+///     limit: u32,
+///     #[getter(skip)]
+///     on_change: Box<dyn FnMut(&'static str)>,
+/// }
+///
+/// let changes = Rc::new(RefCell::new(vec![]));
+/// let log = changes.clone();
+/// let mut model = Model {
+///     count: 0,
+///     limit: 10,
+///     on_change: Box::new(move |field| log.borrow_mut().push(field)),
+/// };
+/// model.set_count(1);
+/// model.set_limit(5);
+/// model.set_count(2);
+/// assert_eq!((model.count(), model.limit()), (2, 5));
+/// assert_eq!(*changes.borrow(), ["count", "limit", "count"]);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Model {
+///     #[getter(observable)]
+///     count: u32,
+/// }
+/// ```
+///
+/// Selecting fields with getters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy, only = "port, timeout", into_parts)]
+/// struct Config {
+///     host: &'static str,
+///     port: u16,
+///     timeout: u32,
+///     #[getter(as_clone)]
+///     secret: String,
+/// }
+///
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy, only = "1,", prefix_numeric_fields)]
+/// struct Pair(u8, u16);
+///
+/// let config = Config::default();
+/// assert_eq!((config.port(), config.timeout()), (0, 0));
+/// assert_eq!(config.into_parts(), (0, 0));
+/// assert_eq!(Pair::default()._1(), 0);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(only = "port")]
+/// struct Config {
+///     host: String,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(only = "host port")]
+/// struct Config {
+///     host: String,
+///     port: u16,
+/// }
+/// ```
+///
+/// Asserting the layout of the structs shared over FFI:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_copy, repr_c_assert)]
+/// #[repr(C)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_eq!(Point { x: 1, y: 2 }.y(), 2);
+/// ```
+///
+/// Non-`repr(C)` structs are rejected:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_copy, repr_c_assert)]
+/// #[repr(packed)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+/// ```
+///
+/// Preallocating collections:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::collections::{HashMap, HashSet};
+///
+/// #[derive(Getters)]
+/// #[getter(with_capacity)]
+/// struct Index {
+///     keys: Vec<u32>,
+///     names: HashMap<u32, String>,
+///     #[getter(skip)]
+///     seen: HashSet<u32>,
+///     #[getter(as_copy)]
+///     version: u8,
+/// }
+///
+/// let index = Index::with_capacity(16);
+/// assert!(index.keys().capacity() >= 16);
+/// assert!(index.names().capacity() >= 16);
+/// assert!(index.seen.capacity() >= 16);
+/// assert!(index.keys().is_empty());
+/// assert_eq!(index.version(), 0);
+/// ```
+///
+/// Asserting thread safety:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::sync::Arc;
+///
+/// #[derive(Getters)]
+/// #[getter(ensure_send_sync)]
+/// struct Shared {
+///     name: Arc<String>,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::rc::Rc;
+///
+/// #[derive(Getters)]
+/// #[getter(ensure_send_sync)]
+/// struct Shared {
+///     name: Rc<String>,
+/// }
+/// ```
+///
+/// Projecting pinned struct into pinned fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::marker::PhantomPinned;
+/// use std::pin::Pin;
+///
+/// #[derive(Getters)]
+/// struct Task {
+///     #[getter(pin)]
+///     state: String,
+///     #[getter(skip)]
+///     _pinned: PhantomPinned,
+/// }
+///
+/// let task = Box::pin(Task { state: String::from("ready"), _pinned: PhantomPinned });
+/// let state: Pin<&String> = task.as_ref().state_pin();
+/// assert_eq!(&*state, "ready");
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Task {
+///     #[getter(pin, as_mut)]
+///     state: String,
+/// }
+/// ```
+///
+/// Borrowing boxed trait objects:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// trait Plugin {
+///     fn name(&self) -> &str;
+/// }
+///
+/// struct Echo;
+/// impl Plugin for Echo {
+///     fn name(&self) -> &str { "echo" }
+/// }
+///
+/// #[derive(Getters)]
+/// #[getter(as_ref, as_mut)]
+/// struct Registry {
+///     plugin: Box<dyn Plugin>,
+///     fallback: Box<dyn Plugin + Send>,
+/// }
+///
+/// let mut registry = Registry { plugin: Box::new(Echo), fallback: Box::new(Echo) };
+/// let plugin: &dyn Plugin = registry.plugin();
+/// assert_eq!(plugin.name(), "echo");
+/// let fallback: &(dyn Plugin + Send) = registry.fallback();
+/// assert_eq!(fallback.name(), "echo");
+/// *registry.plugin_mut() = Box::new(Echo);
+/// ```
+///
+/// Borrowing fields through custom receiver:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::sync::Arc;
+///
+/// #[derive(Getters)]
+/// #[getter(self_ty = "&Arc<Self>")]
+/// struct Config {
+///     name: String,
+///     #[getter(as_slice)]
+///     peers: Vec<u16>,
+/// }
+///
+/// let config = Arc::new(Config { name: String::from("node"), peers: vec![8080] });
+/// assert_eq!(config.name(), "node");
+/// assert_eq!(config.peers(), &[8080]);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::sync::Arc;
+///
+/// #[derive(Getters)]
+/// struct Config {
+///     #[getter(self_ty = "Arc<Self>")]
+///     name: String,
+/// }
+/// ```
+///
+/// Borrowing groups of fields together:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(group = "coords: x, y; size: width, height")]
+/// struct Rect {
+///     x: i32,
+///     y: i32,
+///     #[getter(skip)]
+///     width: u32,
+///     #[getter(skip)]
+///     height: u32,
+/// }
+///
+/// let rect = Rect { x: 1, y: 2, width: 3, height: 4 };
+/// assert_eq!(rect.coords(), (&1, &2));
+/// assert_eq!(rect.size(), (&3, &4));
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(group = "coords: x, z")]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+/// ```
+///
+/// Deriving getters uniformly, including unit structs:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// macro_rules! message {
+///     ($name:ident $($body:tt)*) => {
+///         #[derive(Getters)]
+///         #[getter(allow_empty)]
+///         struct $name $($body)*
+///     };
+/// }
+///
+/// message!(Ping;);
+/// message!(Data { payload: Vec<u8> });
+///
+/// assert_eq!(Data { payload: vec![1] }.payload(), &[1]);
+/// let _ = Ping;
+/// ```
+///
+/// Listing field names:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(field_names)]
+/// struct Point {
+///     x: i32,
+///     #[getter(base_name = "ordinate")]
+///     y: i32,
+///     #[getter(skip)]
+///     cache: Option<i32>,
+/// }
+///
+/// #[derive(Getters)]
+/// #[getter(field_names, prefix_numeric_fields)]
+/// struct Pair(u8, #[getter(skip)] u8, u8);
+///
+/// assert_eq!(Point::FIELDS, &["x", "ordinate"]);
+/// assert_eq!(Pair::FIELDS, &["_0", "_2"]);
+/// ```
+///
+/// Freezing collected data into immutable form:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(freeze)]
+/// pub struct Batch {
+///     ids: Vec<u32>,
+///     #[getter(as_copy)]
+///     size: usize,
+/// }
+///
+/// let batch = Batch { ids: vec![1, 2, 3], size: 3 };
+/// let frozen: FrozenBatch = batch.freeze();
+/// let ids: Box<[u32]> = frozen.ids;
+/// assert_eq!(&ids[..], &[1, 2, 3]);
+/// assert_eq!(frozen.size, 3);
+/// ```
+///
+/// Unwrapping newtypes, with and without validation:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// fn is_lowercase(name: &String) -> Result<(), String> {
+///     if name.chars().any(char::is_uppercase) {
+///         return Err(String::from("must be lowercase"));
+///     }
+///     Ok(())
+/// }
+///
+/// #[derive(Getters)]
+/// #[getter(validate, try_into_inner)]
+/// struct Username(#[getter(base_name = "name", validator = "is_lowercase")] String);
+///
+/// #[derive(Getters)]
+/// #[getter(as_copy, try_into_inner)]
+/// struct Port {
+///     number: u16,
+///     #[getter(skip)]
+///     label: String,
+/// }
+///
+/// assert_eq!(Username(String::from("alice")).try_into_inner().unwrap(), "alice");
+/// let err = Username(String::from("Alice")).try_into_inner().unwrap_err();
+/// assert_eq!(err.message, "must be lowercase");
+///
+/// let port = Port {
+///     number: 8080,
+///     label: String::from("http"),
+/// };
+/// assert_eq!(port.into_inner(), 8080);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(try_into_inner)]
+/// struct Port {
+///     number: u16,
+///     label: String,
+/// }
+/// ```
+///
+/// Destructuring into the field values:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(into_parts)]
+/// struct Request {
+///     method: String,
+///     #[getter(skip)]
+///     retries: u8,
+///     path: String,
+///     body: Vec<u8>,
+/// }
+///
+/// let request = Request {
+///     method: String::from("GET"),
+///     retries: 3,
+///     path: String::from("/"),
+///     body: vec![],
+/// };
+/// let (method, path, body) = request.into_parts();
+/// assert_eq!(method, "GET");
+/// assert_eq!(path, "/");
+/// assert!(body.is_empty());
+///
+/// #[derive(Getters)]
+/// #[getter(into_parts)]
+/// struct Wrapper(#[getter(base_name = "inner")] u64);
+/// let (inner,) = Wrapper(5).into_parts();
+/// assert_eq!(inner, 5);
+/// ```
+///
+/// Round-tripping through the field values:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Clone, PartialEq, Eq, Debug)]
+/// #[getter(into_parts, from_parts)]
+/// struct Endpoint {
+///     host: String,
+///     #[getter(skip)]
+///     cache: Vec<u8>,
+///     port: u16,
+/// }
+///
+/// let endpoint = Endpoint::from_parts((String::from("localhost"), 8080));
+/// assert!(endpoint.cache.is_empty());
+/// assert_eq!(endpoint.port(), &8080);
+/// let (host, port) = endpoint.clone().into_parts();
+/// assert_eq!(Endpoint::from_parts((host, port)), endpoint);
+///
+/// #[derive(Getters, PartialEq, Debug)]
+/// #[getter(as_copy, into_parts, from_parts, prefix_numeric_fields)]
+/// struct Range(u8, u8);
+///
+/// assert_eq!(Range::from_parts(Range(1, 2).into_parts()), Range(1, 2));
+/// ```
+///
+/// Constructing in `const` context:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(const_new, as_copy)]
+/// struct Rect {
+///     width: u32,
+///     height: u32,
+///     #[getter(skip)]
+///     area: u64,
+/// }
+///
+/// const SQUARE: Rect = Rect::new(2, 2, 4);
+/// assert_eq!(SQUARE.width(), 2);
+/// assert_eq!(SQUARE.height(), 2);
+///
+/// #[derive(Getters)]
+/// #[getter(const_new, as_copy)]
+/// struct Point(#[getter(base_name = "x")] i32, #[getter(base_name = "y")] i32);
+///
+/// static ORIGIN: Point = Point::new(0, 0);
+/// assert_eq!(ORIGIN.x() + ORIGIN.y(), 0);
+/// ```
+///
+/// Companion constructors of the types with lifetime parameters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Setters, Clone, PartialEq, Debug)]
+/// #[getter(as_copy, const_new, into_parts, from_parts, merge)]
+/// #[setter(build)]
+/// struct Parser<'a> {
+///     input: Option<&'a str>,
+///     #[getter(skip)]
+///     #[setter(skip)]
+///     pos: usize,
+/// }
+///
+/// const EMPTY: Parser<'static> = Parser::new(None, 0);
+///
+/// fn parser<'a>(input: &'a str) -> Parser<'a> {
+///     Parser::from_parts((None,)).with_input(Some(input))
+/// }
+///
+/// let text = String::from("text");
+/// let mut parser = parser(&text);
+/// parser.merge(EMPTY);
+/// assert_eq!(parser.input(), Some("text"));
+/// assert_eq!(parser.clone().into_parts(), (Some("text"),));
+/// assert_eq!(parser.pos, 0);
+/// ```
+///
+/// Deriving only the companion items:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(no_getters, const_new, field_enum)]
+/// struct Rect {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// const RECT: Rect = Rect::new(3, 4);
+/// assert_eq!(RECT.width * RECT.height, 12);
+/// assert_eq!(RectField::Height.to_string(), "height");
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(no_getters, const_new)]
+/// struct Rect {
+///     width: u32,
+/// }
+///
+/// Rect::new(3).width();
+/// ```
+///
+/// Changing base name of a named field keeps prefix and suffixes:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(prefix = "get_", suffix = "_data")]
+/// struct Stream {
+///     #[getter(as_copy, as_ref = "_ref", as_mut, base_name = "buffer")]
+///     internal_buf: [u8; 4],
+/// }
+///
+/// let mut stream = Stream::default();
+/// stream.get_buffer_data_mut()[0] = 1;
+/// assert_eq!(stream.get_buffer_data(), [1, 0, 0, 0]);
+/// assert_eq!(stream.get_buffer_data_ref(), &[1, 0, 0, 0]);
+/// ```
+///
+/// Structs without fields get no getters, but the companion items are still
+/// derived:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Clone, PartialEq, Debug)]
+/// #[getter(validate, validate_all, diff, snapshot, field_enum)]
+/// #[getter(const_new, partial_eq_subset, gen_tests)]
+/// struct Empty {}
+///
+/// #[derive(Getters, Clone, PartialEq, Debug)]
+/// #[getter(validate, validate_all, diff, snapshot, field_enum)]
+/// #[getter(const_new, partial_eq_subset, trait = "NoGetters")]
+/// struct EmptyTuple();
+///
+/// const EMPTY: Empty = Empty::new();
+/// assert_eq!(EMPTY.validate(), Ok(()));
+/// assert_eq!(EMPTY.validate_all(), Ok(()));
+/// assert!(EMPTY.changed_fields(&Empty {}).is_empty());
+/// assert!(EMPTY.snapshot().is_empty());
+/// assert!(EMPTY.eq_public(&Empty {}));
+/// fn no_fields(field: EmptyField) -> &'static str {
+///     match field {}
+/// }
+///
+/// let empty = EmptyTuple::new();
+/// assert!(empty.validate().is_ok());
+/// assert!(empty.eq_public(&EmptyTuple()));
+/// assert!(empty.snapshot().is_empty());
+/// ```
+///
+/// Borrowing fields which are references:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Parser<'a> {
+///     input: &'a [u8],
+///     #[getter(as_ref, as_mut)]
+///     output: &'a mut Vec<u8>,
+/// }
+///
+/// // The returned slice outlives the parser
+/// fn input<'a>(parser: &Parser<'a>) -> &'a [u8] {
+///     parser.input()
+/// }
+///
+/// let data = [1u8, 2, 3];
+/// let mut buf = vec![];
+/// let mut parser = Parser {
+///     input: &data,
+///     output: &mut buf,
+/// };
+/// parser.output_mut().push(4);
+/// let output: &Vec<u8> = parser.output();
+/// assert_eq!(output, &[4]);
+/// assert_eq!(input(&parser), &[1, 2, 3]);
+/// ```
+///
+/// Borrowing fields as `AsRef` target types:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::path::{Path, PathBuf};
+///
+/// #[derive(Getters)]
+/// struct Config<B> {
+///     #[getter(as_clone, as_ref = "_str", borrow_as = "str")]
+///     name: String,
+///     #[getter(borrow_as = "Path")]
+///     root: PathBuf,
+///     #[getter(borrow_as = "[u8]")]
+///     blob: B,
+///     #[getter(borrow_as = "str", option)]
+///     comment: Option<String>,
+/// }
+///
+/// let config = Config {
+///     name: String::from("main"),
+///     root: PathBuf::from("/srv"),
+///     blob: vec![1u8, 2],
+///     comment: None,
+/// };
+/// let name: &str = config.name_str();
+/// assert_eq!(name, "main");
+/// assert_eq!(config.name(), "main");
+/// assert_eq!(config.root(), Path::new("/srv"));
+/// assert_eq!(config.blob(), &[1, 2]);
+/// assert_eq!(config.comment(), None);
+/// ```
+///
+/// Borrowing wrapper as its inner value:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::borrow::BorrowMut;
+/// use std::collections::HashSet;
+///
+/// #[derive(Getters, Clone, PartialEq, Eq, Hash)]
+/// #[getter(borrow)]
+/// struct Username(#[getter(base_name = "name")] String);
+///
+/// #[derive(Getters)]
+/// struct Account {
+///     #[getter(borrow)]
+///     id: u64,
+///     #[getter(skip)]
+///     balance: u64,
+/// }
+///
+/// let mut users = HashSet::new();
+/// users.insert(Username(String::from("alice")));
+/// assert!(users.contains(&String::from("alice")));
+///
+/// let mut account = Account { id: 1, balance: 0 };
+/// *BorrowMut::<u64>::borrow_mut(&mut account) = 2;
+/// assert_eq!(account.id, 2);
+/// # let _ = account.balance;
+/// ```
+///
+/// Only a single field can be borrowed:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(borrow)]
+/// struct Pair {
+///     first: u8,
+///     second: u16,
+/// }
+/// ```
+///
+/// Deriving getters only for the selected fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(skip, as_copy)]
+/// struct Connection {
+///     #[getter(expose)]
+///     port: u16,
+///     #[getter(expose, as_ref)]
+///     host: String,
+///     retries: u8,
+///     secret: u64,
+/// }
+///
+/// let conn = Connection::default();
+/// assert_eq!(conn.port(), 0);
+/// assert_eq!(conn.host(), "");
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(skip)]
+/// struct Connection {
+///     #[getter(expose)]
+///     port: u16,
+///     secret: u64,
+/// }
+///
+/// Connection::default().secret();
+/// ```
+///
+/// Opting out of the individual methods:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy, as_mut)]
+/// struct Counter {
+///     #[getter(skip_mut)]
+///     total: u64,
+///     #[getter(skip_main)]
+///     step: u64,
+/// }
+///
+/// let mut counter = Counter::default();
+/// *counter.step_mut() = 2;
+/// assert_eq!(counter.total(), 0);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy, as_mut)]
+/// struct Counter {
+///     #[getter(skip_mut)]
+///     total: u64,
+/// }
+///
+/// *Counter::default().total_mut() = 1;
+/// ```
+///
+/// Restricting visibility of the mutable getters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// mod account {
+///     #[derive(Getters, Default)]
+///     #[getter(as_copy, as_mut, mut_vis = "pub(super)")]
+///     pub struct Account {
+///         balance: u64,
+///         #[getter(main_vis = "pub(crate)")]
+///         audits: u32,
+///     }
+/// }
+///
+/// let mut account = account::Account::default();
+/// *account.balance_mut() += 10;
+/// assert_eq!(account.balance(), 10);
+/// assert_eq!(account.audits(), 0);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// mod account {
+///     #[derive(Getters, Default)]
+///     #[getter(as_copy, as_mut, mut_vis = "pub(self)")]
+///     pub struct Account {
+///         balance: u64,
+///     }
+/// }
+///
+/// let mut account = account::Account::default();
+/// *account.balance_mut() += 10;
+/// ```
+///
+/// Controlling inlining of the derived methods:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_clone, inline = "none")]
+/// struct Document {
+///     body: Vec<String>,
+///     #[getter(as_copy, inline = "always")]
+///     version: u16,
+/// }
+///
+/// let doc = Document::default();
+/// assert!(doc.body().is_empty());
+/// assert_eq!(doc.version(), 0);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(inline = "never")]
+/// struct Document {
+///     body: Vec<String>,
+/// }
+/// ```
+///
+/// Allowing lints on the derived methods:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_clone, allow = "clippy::must_use_candidate")]
+/// struct Document {
+///     body: Vec<String>,
+///     #[getter(as_copy, allow = "clippy::must_use_candidate, clippy::inline_always")]
+///     version: u16,
+/// }
+///
+/// let doc = Document::default();
+/// assert_eq!(doc.version(), 0);
+/// ```
+///
+/// Forwarding field attributes to the derived methods:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_copy, forward_attr = "allow")]
+/// #[allow(non_snake_case)]
+/// struct Legacy {
+///     // `maxSize()` getter is not reported by `non_snake_case` lint
+///     #[allow(non_snake_case)]
+///     maxSize: u32,
+/// }
+///
+/// assert_eq!(Legacy::default().maxSize(), 0);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(allow = "clippy::must use")]
+/// struct Document {
+///     body: Vec<String>,
+/// }
+/// ```
+///
+/// Warning on cloning getters of `Copy` fields (made an error here with
+/// `deny(deprecated)`):
+///
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_clone, clone_on_copy_warn)]
+/// struct Counter {
+///     count: u32,
+/// }
+/// ```
+///
+/// ```
+/// #![deny(deprecated)]
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_clone, clone_on_copy_warn)]
+/// struct Counter {
+///     name: String,
+///     #[getter(as_copy)]
+///     count: u32,
+/// }
+///
+/// let counter = Counter {
+///     name: String::from("visits"),
+///     count: 1,
+/// };
+/// assert_eq!(counter.name(), "visits");
+/// assert_eq!(counter.count(), 1);
+/// ```
+///
+/// Comparing values ignoring skipped fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(partial_eq_subset)]
+/// struct Polygon {
+///     points: Vec<(i32, i32)>,
+///     #[getter(skip)]
+///     cached_area: Option<u64>,
+/// }
+///
+/// let fresh = Polygon {
+///     points: vec![(0, 0), (0, 2), (2, 0)],
+///     cached_area: None,
+/// };
+/// let cached = Polygon {
+///     points: vec![(0, 0), (0, 2), (2, 0)],
+///     cached_area: Some(2),
+/// };
+/// let other = Polygon {
+///     points: vec![(0, 0)],
+///     cached_area: None,
+/// };
+/// assert!(fresh.eq_public(&cached));
+/// assert!(!fresh.eq_public(&other));
+/// ```
+///
+/// Combining optional value and error into `Result`:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_result = "output, failure")]
+/// struct Job {
+///     output: Option<u32>,
+///     failure: Option<String>,
+/// }
+///
+/// let done = Job {
+///     output: Some(42),
+///     failure: None,
+/// };
+/// assert_eq!(done.result(), Ok(&42));
+///
+/// let failed = Job {
+///     output: None,
+///     failure: Some(String::from("timeout")),
+/// };
+/// assert_eq!(failed.result(), Err(&String::from("timeout")));
+///
+/// let retried = Job {
+///     output: Some(7),
+///     failure: Some(String::from("timeout")),
+/// };
+/// assert_eq!(retried.result(), Ok(&7));
+/// ```
+///
+/// ```should_panic
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_result = "output, failure")]
+/// struct Job {
+///     output: Option<u32>,
+///     failure: Option<String>,
+/// }
+///
+/// let pending = Job {
+///     output: None,
+///     failure: None,
+/// };
+/// let _ = pending.result();
+/// ```
+///
+/// Taking snapshot of field values:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(snapshot)]
+/// struct Rect {
+///     width: u32,
+///     #[getter(base_name = "label")]
+///     name: Option<&'static str>,
+///     #[getter(skip)]
+///     cached_area: u64,
+/// }
+///
+/// let rect = Rect {
+///     width: 10,
+///     name: Some("square"),
+///     cached_area: 100,
+/// };
+/// assert_eq!(rect.snapshot(), vec![
+///     ("width".to_owned(), "10".to_owned()),
+///     ("label".to_owned(), "Some(\"square\")".to_owned()),
+/// ]);
+///
+/// #[derive(Getters)]
+/// #[getter(snapshot)]
+/// struct Pair(#[getter(base_name = "first")] u8, #[getter(base_name = "second")] bool);
+/// assert_eq!(Pair(1, true).snapshot(), vec![
+///     ("first".to_owned(), "1".to_owned()),
+///     ("second".to_owned(), "true".to_owned()),
+/// ]);
+/// ```
+///
+/// Listing changed fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Clone)]
+/// #[getter(diff)]
+/// struct Profile {
+///     name: String,
+///     #[getter(base_name = "years")]
+///     age: u8,
+///     #[getter(skip)]
+///     visits: u64,
+/// }
+///
+/// let old = Profile {
+///     name: String::from("Alice"),
+///     age: 30,
+///     visits: 1,
+/// };
+/// let mut new = old.clone();
+/// assert!(new.changed_fields(&old).is_empty());
+///
+/// new.age = 31;
+/// new.visits = 2;
+/// assert_eq!(new.changed_fields(&old), vec!["years"]);
+/// new.name = String::from("Bob");
+/// assert_eq!(new.changed_fields(&old), vec!["name", "years"]);
+/// ```
+///
+/// Checksums of byte fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Clone)]
+/// #[getter(checksum)]
+/// struct Packet {
+///     #[getter(checksum_include)]
+///     header: [u8; 4],
+///     #[getter(checksum_include)]
+///     payload: Vec<u8>,
+///     retries: u8,
+/// }
+///
+/// let packet = Packet {
+///     header: [0xCA, 0xFE, 0xBA, 0xBE],
+///     payload: vec![1, 2, 3],
+///     retries: 0,
+/// };
+/// let checksum = packet.checksum();
+///
+/// let mut other = packet.clone();
+/// other.retries = 3;
+/// assert_eq!(other.checksum(), checksum);
+/// other.payload.push(4);
+/// assert_ne!(other.checksum(), checksum);
+///
+///
+/// // Field boundaries are part of the checksum
+/// #[derive(Getters)]
+/// #[getter(checksum)]
+/// struct Names {
+///     #[getter(checksum_include)]
+///     first: String,
+///     #[getter(checksum_include)]
+///     last: String,
+/// }
+///
+/// let names = |first: &str, last: &str| Names {
+///     first: first.to_owned(),
+///     last: last.to_owned(),
+/// };
+/// assert_ne!(names("ab", "c").checksum(), names("a", "bc").checksum());
+/// assert_eq!(names("ab", "c").checksum(), names("ab", "c").checksum());
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Packet {
+///     #[getter(checksum_include)]
+///     payload: Vec<u8>,
+/// }
+/// ```
+///
+/// Getters placed into a trait:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(trait = "HasCoords", as_ref, as_mut)]
+/// pub struct Point<T>
+/// where
+///     T: Copy,
+/// {
+///     /// Horizontal coordinate
+///     x: T,
+///     /// Vertical coordinate
+///     y: T,
+/// }
+///
+/// fn shifted(point: &mut dyn HasCoords<i32>) -> (i32, i32) {
+///     *point.x_mut() += 1;
+///     (*point.x(), *point.y())
+/// }
+///
+/// let mut point = Point::<i32>::default();
+/// assert_eq!(shifted(&mut point), (1, 0));
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(trait = "HasLimit", as_copy, const)]
+/// struct Limits {
+///     max: usize,
+/// }
+/// ```
+///
+/// Conditionally derived getters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(cfg = "not(feature = \"accessors\")", field_enum)]
+/// struct Enabled {
+///     port: u16,
+/// }
+///
+/// #[derive(Getters, Default)]
+/// #[getter(cfg = "feature = \"accessors\"", as_copy, field_enum)]
+/// struct Disabled {
+///     port: u16,
+/// }
+///
+/// // The names are free, since nothing is derived for `Disabled`
+/// impl Disabled {
+///     fn port(&self) -> u16 { self.port + 1 }
+/// }
+/// struct DisabledField;
+///
+/// assert_eq!(Enabled::default().port(), &0);
+/// assert_eq!(EnabledField::Port.to_string(), "port");
+/// assert_eq!(Disabled::default().port(), 1);
+/// # let _ = DisabledField;
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(cfg = "feature = ")]
+/// struct Limits {
+///     max: usize,
+/// }
+/// ```
+///
+/// Delegating to the dereferenced type:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// # use std::ops::Deref;
+/// #[derive(Getters)]
+/// struct Rect {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// #[derive(Getters)]
+/// #[getter(delegate_deref = "width: &u32, height: &u32")]
+/// struct Window {
+///     rect: Rect,
+///     title: String,
+/// }
+///
+/// impl Deref for Window {
+///     type Target = Rect;
+///     fn deref(&self) -> &Rect { &self.rect }
+/// }
+///
+/// let window = Window {
+///     rect: Rect {
+///         width: 640,
+///         height: 480,
+///     },
+///     title: String::from("main"),
+/// };
+/// assert_eq!(window.width(), &640);
+/// assert_eq!(window.height(), &480);
+/// assert_eq!(window.title(), "main");
+/// ```
+///
+/// Enums with newtype variants:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_ref, as_mut)]
+/// enum Msg {
+///     Ping(u32),
+///     Pong(String),
+///     #[getter(skip)]
+///     Close,
+///     #[getter(as_copy)]
+///     MaxSize(u64),
+/// }
+///
+/// let mut msg = Msg::Ping(5);
+/// assert_eq!(msg.as_ping(), Some(&5));
+/// assert_eq!(msg.as_pong(), None);
+/// *msg.as_ping_mut().unwrap() = 6;
+/// assert_eq!(msg.as_ping(), Some(&6));
+/// assert_eq!(Msg::Pong(String::from("pong")).as_pong(), Some(&String::from("pong")));
+/// assert_eq!(Msg::MaxSize(10).as_max_size(), Some(10));
+/// assert_eq!(Msg::Close.as_max_size(), None);
+/// ```
+///
+/// Moving out enum variant data:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, PartialEq, Debug)]
+/// #[getter(try_into)]
+/// enum Value {
+///     Number(u32),
+///     Text(String),
+/// }
+///
+/// assert_eq!(Value::Number(5).try_into_number(), Ok(5));
+/// let value = Value::Number(5).try_into_text().unwrap_err();
+/// assert_eq!(value, Value::Number(5));
+/// assert_eq!(Value::Text(String::from("text")).try_into_text(), Ok(String::from("text")));
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(try_into)]
+/// struct Value {
+///     number: u32,
+/// }
+/// ```
+///
+/// Misspelled argument names are reported with a suggestion of the closest
+/// known argument (here "unknown getter argument `as_reff`; did you mean
+/// `as_ref`?"):
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Msg {
+///     #[getter(as_reff)]
+///     data: Vec<u8>,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(prefx = "get_")]
+/// struct Msg {
+///     data: Vec<u8>,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_clne)]
+/// struct Msg {
+///     data: Vec<u8>,
+/// }
+/// ```
+///
+/// Getter methods with the same name derived for different fields are reported
+/// at the field causing the clash:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Rect {
+///     width: u32,
+///     #[getter(base_name = "width")]
+///     height: u32,
+/// }
+/// ```
+///
+/// unless the fields are compiled under different conditions:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_copy)]
+/// struct Handle {
+///     #[cfg(unix)]
+///     fd: i32,
+///     #[cfg(not(unix))]
+///     #[getter(base_name = "fd")]
+///     handle: i32,
+/// }
+///
+/// # #[cfg(unix)]
+/// assert_eq!(Handle { fd: 3 }.fd(), 3);
+/// ```
+///
+/// The same applies to the methods of different kinds derived for a single
+/// field, if their suffixes do not distinguish them:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// #[getter(as_ref, as_mut = "")]
+/// struct Rect {
+///     width: u32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters)]
+/// struct Rect {
+///     #[getter(as_clone = "_value", as_ref = "_value", base_name = "size")]
+///     width: u32,
+/// }
+/// ```
+///
+/// Advanced use: please pay attention that `as_mut` on a struct level is not
+/// removed by the use of `as_copy` at field level.
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Default)]
+/// #[getter(as_mut, prefix = "get_")]
+/// struct One {
+///     /// Contains byte representation of the data
+///     #[getter(all, base_name = "bytes")]
+///     vec: Vec<u8>,
+///
+///     defaults: String,
+///
+///     #[getter(as_copy)]
+///     pub flag: bool,
+///
+///     #[getter(skip)]
+///     pub(self) field: u8,
+/// }
+///
+/// let mut one = One::default();
+/// assert_eq!(one.get_bytes_ref(), &Vec::<u8>::default());
+/// *one.get_bytes_mut() = vec![0, 1, 2];
+/// assert_eq!(one.get_defaults(), "");
+/// assert_eq!(one.get_defaults_mut(), "");
+/// assert_eq!(one.get_bytes(), vec![0, 1, 2]);
+/// assert_eq!(one.get_flag(), bool::default());
+/// assert_eq!(one.get_flag_mut(), &mut bool::default());
+/// let flag = one.get_flag_mut();
+/// *flag = true;
+/// assert_eq!(one.get_flag(), true);
+/// assert_eq!(one.flag, one.get_flag());
+/// // method does not exist: assert_eq!(one.get_field(), u8::default());
+/// ```
+///
+/// this will end up in the following generated code:
+/// ```
+/// # struct One {
+/// #    vec: Vec<u8>,
+/// #    pub flag: bool,
+/// #    pub(self) field: u8,
+/// # }
+///
+/// impl One {
+///     #[doc = "Method cloning [`One::vec`] field.\n"]
+///     #[doc = " Contains byte representation of the data"]
+///     #[inline]
+///     pub fn get_bytes(&self) -> Vec<u8> { self.vec.clone() }
+///
+///     #[doc = "Method borrowing [`One::vec`] field.\n"]
+///     #[doc = " Contains byte representation of the data"]
+///     #[inline]
+///     pub fn get_bytes_ref(&self) -> &Vec<u8> { &self.vec }
+///
+///     #[doc = "Method returning mutable borrow of [`One::vec`] field.\n"]
+///     #[doc = " Contains byte representation of the data"]
+///     #[inline]
+///     pub fn get_bytes_mut(&mut self) -> &mut Vec<u8> { &mut self.vec }
+///
+///     #[doc = "Method returning copy of [`One::flag`] field.\n"]
+///     #[inline]
+///     pub fn get_flag(&self) -> bool { self.flag }
+///
+///     #[doc = "Method returning mutable borrow of [`One::flag`] field.\n"]
+///     #[inline]
+///     pub fn get_flag_mut(&mut self) -> &mut bool { &mut self.flag }
+/// }
+/// ```
+#[proc_macro_derive(Getters, attributes(getter, accessor))]
+pub fn derive_getters(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    getters::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives setter methods for structures, mirroring [`Getters`] derive macro.
+/// For each of the fields a method assigning a new value to the field is
+/// generated.
+///
+/// # Attribute `#[setter(...)]`
+///
+/// Macro is provided with `#[setter]` attribute, which may be used on both
+/// type and field level. See following sections describing its arguments
+///
+/// ## Arguments
+///
+/// Arguments shared with [`Getters`] derive may be provided with
+/// `#[accessor(...)]` attribute, as described in the docs of [`Getters`].
+///
+/// ### `#[setter(skip)]`
+/// Skips derivation of the setter method for this field
+///
+/// ### `#[setter(prefix = "...")]`
+/// Defines prefix added to all derived setter method names.
+///
+/// **Defaults to**: `set_`
+///
+/// **Can be used**: at type level
+///
+/// ### `#[setter(base_name = "...")]`
+/// Defines base name for the setter method. Base name is prefixed with prefix
+/// from a type-level setter `prefix` attribute.
+///
+/// **Defaults to**: field name
+///
+/// **Required for**: unnamed fields of tuple structs
+///
+/// **Can be used**: at field level
+///
+/// ### `#[setter(vis = "...")]`
+/// Defines visibility of the derived methods, like `pub(crate)`; an empty
+/// string makes the methods private.
+///
+/// **Defaults to**: `pub`
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[setter(chain)]`
+/// Makes setter methods return `&mut Self`, so the calls can be chained, like
+/// in `config.set_name(name).set_port(port)`. Unlike builder methods, chained
+/// setters borrow the value mutably instead of taking it by value.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[setter(into)]`
+/// Makes setter methods (including the ones derived with `build`) accept any
+/// value convertible into the field type, like `&str` for `String` fields,
+/// with `value: impl Into<Field>` argument.
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[setter(replace = "...")]`
+/// Additionally derives methods assigning new value to the field and returning
+/// the previous one, like `fn replace_state(&mut self, state: State) -> State`,
+/// which is useful for state machines. The argument value defines prefix of
+/// these methods. With `try_with` the values are validated, and the methods
+/// return `Result<State, _>`.
+///
+/// **Defaults to**: `replace_` prefix
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[setter(push)]`
+/// Additionally derives `push_{field}(&mut self, item: T)` method appending
+/// item to the field of `Vec<T>` type. Returns `&mut Self` with `chain`.
+///
+/// **Can be used**: at field level
+///
+/// ### `#[setter(insert)]`
+/// Additionally derives `insert_{field}(&mut self, key: K, value: V)` method
+/// inserting key-value pair into the field of `HashMap<K, V>` type. Returns
+/// `&mut Self` with `chain`.
+///
+/// **Can be used**: at field level
+///
+/// ### `#[setter(try_with = "...")]`
+/// Validates the value before assigning it with the function at the given
+/// path, which takes reference to the value and returns `Result<_, E>`. The
+/// setter then returns `Result`, leaving the field unchanged and returning the
+/// error if the validation fails; the error is converted into the type
+/// provided with `try_error` argument, which is required. Chained setters
+/// return `Result<&mut Self, _>`, builder methods return `Result<Self, _>` and
+/// `replace` methods return `Result` with the previous value. Can't be
+/// combined with `push` and `insert`, which modify the field in place.
+///
+/// **Can be used**: at field level
+///
+/// ### `#[setter(try_error = "...")]`
+/// Error type returned by the setters validating values with `try_with`.
+///
+/// **Required for**: `try_with`
+///
+/// **Can be used**: at type and field level
+///
+/// ### `#[setter(build = "...")]`
+/// Additionally derives builder-style methods consuming the value and returning
+/// it with the new field value, like `fn with_port(mut self, port: u16) ->
+/// Self`, for constructing values fluently. The argument value defines prefix of
+/// these methods, replacing the type-level `prefix`.
+///
+/// **Defaults to**: `with_` prefix
+///
+/// **Can be used**: at type and field level
+///
+/// # Errors
+///
+/// Enums, unions and unit structs are not supported; attempt to derive
+/// `Setters` on them will result in a compile-time error. Unnamed fields
+/// without `base_name` argument also result in an error.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Setters, Default)]
+/// struct Config {
+///     /// Name of the service
+///     name: String,
+///     #[getter(as_copy)]
+///     port: u16,
+///     #[setter(skip)]
+///     version: u8,
+/// }
+///
+/// let mut config = Config::default();
+/// config.set_name(String::from("service"));
+/// config.set_port(8080);
+/// assert_eq!(config.name(), "service");
+/// assert_eq!(config.port(), 8080);
+/// // method does not exist: config.set_version(1);
+///
+/// #[derive(Setters, Default)]
+/// #[setter(prefix = "put_")]
+/// struct Pair(#[setter(base_name = "first")] u8, #[setter(base_name = "second")] u16);
+///
+/// let mut pair = Pair::default();
+/// pair.put_first(1);
+/// pair.put_second(2);
+/// assert_eq!((pair.0, pair.1), (1, 2));
+/// ```
+///
+/// Raw identifier fields get setters without `r#` prefix:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// # use std::collections::HashMap;
+/// #[derive(Setters, Default)]
+/// #[setter(prefix = "")]
+/// struct Token {
+///     #[setter(build, replace)]
+///     r#type: u8,
+///     #[setter(push)]
+///     r#ref: Vec<u8>,
+///     #[setter(insert)]
+///     r#match: HashMap<u8, u8>,
+/// }
+///
+/// let mut token = Token::default().with_type(1);
+/// token.r#type(2);
+/// assert_eq!(token.replace_type(3), 2);
+/// token.push_ref(4);
+/// token.insert_match(5, 6);
+/// assert_eq!((token.r#type, token.r#ref, token.r#match[&5]), (3, vec![4], 6));
+/// ```
+///
+/// Chaining setter calls:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Setters, Default)]
+/// #[setter(chain)]
+/// struct Window {
+///     width: u32,
+///     height: u32,
+///     title: String,
+/// }
+///
+/// #[derive(Setters, Default)]
+/// struct Cursor {
+///     #[setter(chain)]
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// let mut window = Window::default();
+/// window
+///     .set_width(800)
+///     .set_height(600)
+///     .set_title(String::from("main"));
+/// assert_eq!((window.width, window.height), (800, 600));
+/// assert_eq!(window.title, "main");
+///
+/// let mut cursor = Cursor::default();
+/// cursor.set_x(1).set_y(2);
+/// let () = cursor.set_y(3);
+/// assert_eq!((cursor.x, cursor.y), (1, 3));
+/// ```
+///
+/// Constructing values fluently:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Setters, Default)]
+/// #[setter(build)]
+/// struct Config {
+///     #[getter(as_copy)]
+///     port: u16,
+///     #[setter(build = "and_")]
+///     host: String,
+///     #[setter(skip)]
+///     version: u8,
+/// }
+///
+/// let mut config = Config::default()
+///     .with_port(8080)
+///     .and_host(String::from("localhost"));
+/// assert_eq!(config.port(), 8080);
+/// assert_eq!(config.host(), "localhost");
+/// config.set_port(80);
+/// assert_eq!(config.port(), 80);
+/// # let _ = config.version;
+/// ```
+///
+/// Setters accepting convertible values, including generic fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Setters, Default)]
+/// #[setter(into, chain, build)]
+/// struct Request<T> {
+///     url: String,
+///     body: T,
+///     #[setter(skip)]
+///     retries: u8,
+/// }
+///
+/// let mut request = Request::<Vec<u8>>::default().with_url("http://localhost");
+/// request.set_url("http://example.com").set_body(&b"ping"[..]);
+/// assert_eq!(request.url, "http://example.com");
+/// assert_eq!(request.body, b"ping");
+/// # let _ = request.retries;
+/// ```
+///
+/// Replacing values:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Setters)]
+/// struct Machine {
+///     #[setter(replace, into)]
+///     state: String,
+///     #[setter(replace = "swap_")]
+///     step: u32,
+/// }
+///
+/// let mut machine = Machine {
+///     state: String::from("idle"),
+///     step: 0,
+/// };
+/// assert_eq!(machine.replace_state("running"), "idle");
+/// assert_eq!(machine.swap_step(1), 0);
+/// assert_eq!(machine.replace_state("done"), "running");
+/// assert_eq!(machine.step, 1);
+/// ```
+///
+/// Sharing arguments with getters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Setters, Default)]
+/// #[getter(as_copy)]
+/// struct Point(
+///     #[accessor(base_name = "x")] i32,
+///     #[accessor(base_name = "y")]
+///     #[setter(base_name = "ordinate")]
+///     i32,
+///     #[accessor(skip)] u8,
+/// );
+///
+/// let mut point = Point::default();
+/// point.set_x(1);
+/// point.set_ordinate(2);
+/// assert_eq!((point.x(), point.y()), (1, 2));
+/// # let _ = point.2;
+/// ```
+///
+/// Shared prefix and visibility:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// mod config {
+///     #[derive(Getters, Setters, Default)]
+///     #[accessor(prefix = "max_", vis = "pub(crate)")]
+///     #[getter(as_copy)]
+///     pub struct Config {
+///         connections: u16,
+///         #[accessor(prefix = "min_")]
+///         threads: u8,
+///         #[setter(prefix = "limit_")]
+///         #[getter(vis = "pub")]
+///         retries: u8,
+///     }
+/// }
+///
+/// let mut config = config::Config::default();
+/// config.set_max_connections(8);
+/// config.set_min_threads(2);
+/// config.limit_retries(3);
+/// assert_eq!(config.max_connections(), 8);
+/// assert_eq!(config.min_threads(), 2);
+/// assert_eq!(config.max_retries(), 3);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Getters, Setters)]
+/// #[accessor(skip)]
+/// struct Point {
+///     x: i32,
+/// }
+/// ```
+///
+/// Element-level setters of collection fields:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use std::collections::HashMap;
+///
+/// #[derive(Setters, Default)]
+/// struct Request {
+///     #[setter(push, chain)]
+///     path: Vec<String>,
+///     #[setter(insert)]
+///     headers: HashMap<String, String>,
+/// }
+///
+/// let mut request = Request::default();
+/// request
+///     .push_path(String::from("api"))
+///     .push_path(String::from("v1"));
+/// request.insert_headers(String::from("Accept"), String::from("*/*"));
+/// assert_eq!(request.path, ["api", "v1"]);
+/// assert_eq!(request.headers["Accept"], "*/*");
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Setters)]
+/// struct Request {
+///     #[setter(push)]
+///     path: String,
+/// }
+/// ```
+///
+/// Validating setters:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct InvalidPort(u16);
+///
+/// fn non_zero(port: &u16) -> Result<(), InvalidPort> {
+///     if *port == 0 {
+///         return Err(InvalidPort(*port));
+///     }
+///     Ok(())
+/// }
+///
+/// #[derive(Setters, Default)]
+/// #[setter(build, replace, try_error = "InvalidPort")]
+/// struct Endpoint {
+///     #[setter(try_with = "non_zero")]
+///     port: u16,
+///     host: String,
+/// }
+///
+/// let mut endpoint = Endpoint::default().with_port(80).unwrap();
+/// assert_eq!(endpoint.set_port(0), Err(InvalidPort(0)));
+/// assert_eq!(endpoint.replace_port(0), Err(InvalidPort(0)));
+/// assert_eq!(endpoint.port, 80);
+/// assert_eq!(endpoint.replace_port(443), Ok(80));
+/// endpoint.set_port(8080).unwrap();
+/// assert_eq!(endpoint.replace_host(String::from("localhost")), "");
+/// assert_eq!((endpoint.port, endpoint.host.as_str()), (8080, "localhost"));
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// # fn non_zero(port: &u16) -> Result<(), ()> { Ok(()) }
+/// #[derive(Setters)]
+/// struct Endpoint {
+///     #[setter(try_with = "non_zero")]
+///     port: u16,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Setters)]
+/// struct Endpoint {
+///     #[setter(try_with, try_error = "()")]
+///     port: u16,
+/// }
+/// ```
+#[proc_macro_derive(Setters, attributes(setter, accessor))]
+pub fn derive_setters(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    setters::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Creates rust new type wrapping existing type. Can be used in structures
+/// containing multiple named or unnamed fields; in this case the field you'd
+/// like to wrap should be marked with `#[wrap]` attribute; otherwise the first
+/// field is assumed to be the wrapped one.
+///
+/// NB: You have to use `derive(From)` in order foe Wrapper to work properly.
+/// Also, in case of multiple fields, each non-wrapped field type must implement
+/// `Default` trait.
+///
+/// Supports automatic implementation of the following traits:
+/// * `amplify::Wrapper`
+/// * [`AsRef`]
+/// * [`core::borrow::Borrow`]
+///
+/// You may skip `AsRef` and `Borrow` implementations with `#[wrapper(NoRefs)]`.
+///
+/// You can implement additional derives, it they are implemented for the
+/// wrapped type, using `#[wrapper()]` proc macro:
+/// 1. Reference access to the inner type:
 ///    * `Deref` for implementing [`core::ops::Deref`]
 ///    * `AsSlice` for implementing [`AsRef`]`<[u8]>`
 ///    * `BorrowSlice` for implementing
@@ -750,6 +4869,25 @@ pub fn derive_getters(input: TokenStream) -> TokenStream {
 /// #[wrapper(Index, RangeOps)]
 /// struct VecNewtype(Vec<u8>);
 /// ```
+///
+/// Forwarding selected arithmetic operators to the inner value:
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Wrapper, Clone, Copy, PartialEq, Eq, Debug, From)]
+/// #[wrapper(Add, Sub)]
+/// struct Sats(u64);
+///
+/// assert_eq!(Sats(5) + Sats(3), Sats(8));
+/// assert_eq!(Sats(5) - Sats(3), Sats(2));
+/// ```
+///
+/// Operators must be implemented by the inner type:
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Wrapper, From)]
+/// #[wrapper(Sub)]
+/// struct Name(String);
+/// ```
 #[proc_macro_derive(Wrapper, attributes(wrap, wrapper, amplify_crate))]
 pub fn derive_wrapper(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -766,6 +4904,7 @@ pub fn derive_wrapper(input: TokenStream) -> TokenStream {
 /// * `amplify::WrapperMut`
 /// * [`AsMut`]
 /// * [`core::borrow::BorrowMut`]
+///
 /// You may skip `AsMut` and `BorrowMut` implementations with
 /// `#[wrapper_mut(NoRefs)]`.
 ///
@@ -830,6 +4969,30 @@ pub fn derive_wrapper(input: TokenStream) -> TokenStream {
 /// #[wrapper_mut(MathAssign, BitAssign)]
 /// struct Int64(i64);
 /// ```
+///
+/// Mutating the wrapped value:
+///
+/// ```
+/// # #[macro_use] extern crate amplify_derive;
+/// use amplify::{Wrapper, WrapperMut};
+///
+/// #[derive(Wrapper, WrapperMut, Default, From)]
+/// #[wrapper(Deref)]
+/// #[wrapper_mut(DerefMut)]
+/// struct Stack(Vec<u8>);
+///
+/// let mut stack = Stack::default();
+/// stack.as_inner_mut().push(1);
+/// stack.push(2);
+/// *stack.last_mut().unwrap() += 1;
+/// assert_eq!(stack.into_inner(), vec![1, 3]);
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate amplify_derive;
+/// #[derive(Wrapper, WrapperMut, From)]
+/// struct Point(i32, i32);
+/// ```
 #[proc_macro_derive(WrapperMut, attributes(wrap, wrapper_mut, amplify_crate))]
 pub fn derive_wrapper_mut(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
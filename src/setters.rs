@@ -0,0 +1,552 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::iter::FromIterator;
+
+use amplify_syn::{ArgValue, ArgValueReq, AttrReq, ParametrizedAttr, ValueClass};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DataStruct, DeriveInput, Error, Field, Fields, ImplGenerics, Index, LitStr,
+    Path, Result, Type, TypeGenerics, Visibility, WhereClause,
+};
+
+use crate::util::{
+    accessor_args, accessor_prefix, generic_type_args, map_key_value, merge_accessor_args,
+    try_ident, type_last_segment,
+};
+
+pub(crate) fn derive(input: DeriveInput) -> Result<TokenStream2> {
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let struct_name = &input.ident;
+
+    let type_accessor = accessor_args(&input.attrs, true)?;
+    let mut global_param = ParametrizedAttr::with("setter", &input.attrs)?;
+    merge_accessor_args(&mut global_param.args, &type_accessor);
+    let _ = SetterDerive::try_from(&mut global_param, true)?;
+
+    match input.data {
+        Data::Struct(data) => derive_struct_impl(
+            data,
+            struct_name,
+            global_param,
+            &type_accessor,
+            impl_generics,
+            ty_generics,
+            where_clause,
+        ),
+        Data::Enum(_) => {
+            Err(Error::new_spanned(&input, "Deriving setters is not supported in enums"))
+        }
+        Data::Union(_) => {
+            Err(Error::new_spanned(&input, "Deriving setters is not supported in unions"))
+        }
+    }
+}
+
+#[derive(Clone)]
+struct SetterDerive {
+    pub prefix: LitStr,
+    pub skip: bool,
+    pub chain: bool,
+    pub into: bool,
+    /// Derive `push_` method for `Vec` field
+    pub push: bool,
+    /// Derive `insert_` method for `HashMap` field
+    pub insert: bool,
+    pub build: Option<LitStr>,
+    pub replace: Option<LitStr>,
+    pub base: Option<LitStr>,
+    /// Function validating the value before it is assigned
+    pub try_with: Option<Path>,
+    /// Error type returned by the validating setters
+    pub try_error: Option<Type>,
+    /// Visibility of the derived methods, if it differs from `pub`
+    pub vis: Option<Visibility>,
+}
+
+impl SetterDerive {
+    fn try_from(attr: &mut ParametrizedAttr, global: bool) -> Result<SetterDerive> {
+        let mut map = HashMap::from_iter(vec![
+            ("prefix", ArgValueReq::with_default("set_")),
+            ("chain", ArgValueReq::Prohibited),
+            ("into", ArgValueReq::Prohibited),
+            ("build", ArgValueReq::with_default("with_")),
+            ("replace", ArgValueReq::with_default("replace_")),
+            ("try_error", ArgValueReq::Optional(ValueClass::str())),
+            ("vis", ArgValueReq::Optional(ValueClass::str())),
+        ]);
+
+        if !global {
+            map.insert("skip", ArgValueReq::Prohibited);
+            map.insert("push", ArgValueReq::Prohibited);
+            map.insert("insert", ArgValueReq::Prohibited);
+            map.insert("base_name", ArgValueReq::Optional(ValueClass::str()));
+            map.insert("try_with", ArgValueReq::Optional(ValueClass::str()));
+        }
+
+        // Reported before the generic type mismatch error of the check
+        for (name, expected) in
+            [("try_with", "path to the validating function"), ("try_error", "error type")]
+        {
+            if attr.paths.iter().any(|path| path.is_ident(name)) {
+                return Err(Error::new(
+                    Span::call_site(),
+                    format!(
+                        "`{}` setter argument requires {}, like `#[setter({} = \"...\")]`",
+                        name, expected, name
+                    ),
+                ));
+            }
+        }
+        attr.check(AttrReq::with(map))?;
+
+        Ok(SetterDerive {
+            prefix: attr
+                .args
+                .get("prefix")
+                .map(|a| a.clone().try_into())
+                .transpose()?
+                .unwrap_or_else(|| LitStr::new("set_", Span::call_site())),
+            skip: attr.args.contains_key("skip"),
+            chain: attr.args.contains_key("chain"),
+            into: attr.args.contains_key("into"),
+            push: attr.args.contains_key("push"),
+            insert: attr.args.contains_key("insert"),
+            build: attr
+                .args
+                .get("build")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            replace: attr
+                .args
+                .get("replace")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            base: attr
+                .args
+                .get("base_name")
+                .map(|a| a.clone().try_into())
+                .transpose()?,
+            try_with: parse_arg(attr, "try_with")?,
+            try_error: parse_arg(attr, "try_error")?,
+            vis: parse_arg(attr, "vis").map_err(|err| {
+                Error::new(
+                    err.span(),
+                    "`vis` setter argument must be a visibility, like `pub` or `pub(crate)`",
+                )
+            })?,
+        })
+    }
+
+    /// Visibility of the derived methods
+    pub fn vis(&self) -> TokenStream2 {
+        match self.vis {
+            Some(ref vis) => vis.to_token_stream(),
+            None => quote! { pub },
+        }
+    }
+
+    pub fn setter_fn_ident(
+        &self,
+        prefix: &LitStr,
+        field_name: Option<&Ident>,
+        span: Span,
+    ) -> Result<Ident> {
+        let base_string = self
+            .base
+            .as_ref()
+            .map(LitStr::value)
+            .or_else(|| field_name.map(|ident| ident.unraw().to_string()))
+            .ok_or_else(|| {
+                Error::new(
+                    span,
+                    "Unnamed fields must be equipped with `#[setter(base_name = \"name\"]` \
+                     attribute",
+                )
+            })?;
+
+        let s = format!("{}{}", prefix.value(), base_string);
+
+        try_ident(&s, span).ok_or_else(|| {
+            Error::new(
+                span,
+                format!("derived setter method name `{}` is not a valid Rust identifier", s),
+            )
+        })
+    }
+
+    /// Element-level method of a collection field, chained if `chain` is used
+    pub fn collection_fn(
+        &self,
+        fn_doc: String,
+        fn_name: Ident,
+        args: TokenStream2,
+        body: TokenStream2,
+    ) -> TokenStream2 {
+        let vis = self.vis();
+        if self.chain {
+            quote! {
+                #[doc = #fn_doc]
+                #[inline]
+                #vis fn #fn_name(&mut self, #args) -> &mut Self {
+                    #body
+                    self
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #fn_doc]
+                #[inline]
+                #vis fn #fn_name(&mut self, #args) {
+                    #body
+                }
+            }
+        }
+    }
+
+    pub fn setter_fn_doc(
+        &self,
+        struct_name: &Ident,
+        field_name: Option<&Ident>,
+        field_index: usize,
+        field_doc: Option<&Attribute>,
+    ) -> TokenStream2 {
+        let mut fn_doc = format!(
+            "Method assigning new value to [`{}::{}`] field.\n",
+            struct_name,
+            field_name
+                .map(Ident::to_string)
+                .unwrap_or_else(|| field_index.to_string())
+        );
+        if self.chain {
+            fn_doc
+                .push_str("\nReturns mutable reference to `self`, allowing to chain the calls.\n");
+        }
+        if self.try_with.is_some() {
+            fn_doc.push_str(
+                "\nThe value is validated before the assignment; if the validation fails, the \
+                 field is left unchanged and the validation error is returned.\n",
+            );
+        }
+
+        if let Some(field_doc) = field_doc {
+            quote! {
+                #[doc = #fn_doc]
+                #field_doc
+            }
+        } else {
+            quote! {
+                #[doc = #fn_doc]
+            }
+        }
+    }
+}
+
+/// Parses string value of the argument as Rust syntax
+fn parse_arg<T: syn::parse::Parse>(attr: &ParametrizedAttr, name: &str) -> Result<Option<T>> {
+    attr.args
+        .get(name)
+        .map(|a| a.clone().try_into())
+        .transpose()?
+        .map(|lit: LitStr| lit.parse())
+        .transpose()
+}
+
+fn derive_struct_impl(
+    data: DataStruct,
+    struct_name: &Ident,
+    global_param: ParametrizedAttr,
+    type_accessor: &HashMap<String, ArgValue>,
+    impl_generics: ImplGenerics,
+    ty_generics: TypeGenerics,
+    where_clause: Option<&WhereClause>,
+) -> Result<TokenStream2> {
+    let mut methods = Vec::with_capacity(data.fields.len());
+    match data.fields {
+        Fields::Named(ref fields) => {
+            for (index, field) in fields.named.iter().enumerate() {
+                methods.extend(derive_field_methods(
+                    field,
+                    index,
+                    struct_name,
+                    &global_param,
+                    type_accessor,
+                )?)
+            }
+        }
+        Fields::Unnamed(ref fields) => {
+            for (index, field) in fields.unnamed.iter().enumerate() {
+                methods.extend(derive_field_methods(
+                    field,
+                    index,
+                    struct_name,
+                    &global_param,
+                    type_accessor,
+                )?)
+            }
+        }
+        Fields::Unit => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Deriving setters is meaningless for unit structs",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #( #methods )*
+        }
+    })
+}
+
+fn derive_field_methods(
+    field: &Field,
+    index: usize,
+    struct_name: &Ident,
+    global_param: &ParametrizedAttr,
+    type_accessor: &HashMap<String, ArgValue>,
+) -> Result<Vec<TokenStream2>> {
+    let mut local_param = ParametrizedAttr::with("setter", &field.attrs)?;
+    let accessor = accessor_args(&field.attrs, false)?;
+    merge_accessor_args(&mut local_param.args, &accessor);
+
+    // First, test individual attribute
+    let _ = SetterDerive::try_from(&mut local_param, false)?;
+    let prefix =
+        accessor_prefix(&global_param.args, &local_param.args, "set_", &accessor, type_accessor)?;
+    // Second, combine global and local together
+    let mut params = global_param.clone().merged(local_param)?;
+    if let Some(prefix) = prefix {
+        params.args.insert("prefix".to_owned(), prefix);
+    }
+    let setter = SetterDerive::try_from(&mut params, false)?;
+
+    if setter.skip {
+        return Ok(Vec::new());
+    }
+
+    let field_name = field.ident.as_ref();
+    let field_access = match field_name {
+        Some(ident) => quote! { #ident },
+        None => Index::from(index).to_token_stream(),
+    };
+    let ty = &field.ty;
+    let (arg_ty, value) = if setter.into {
+        (quote! { impl ::core::convert::Into<#ty> }, quote! { value.into() })
+    } else {
+        (ty.to_token_stream(), quote! { value })
+    };
+    let doc = field.attrs.iter().find(|a| a.path.is_ident("doc"));
+
+    let fn_name = setter.setter_fn_ident(&setter.prefix, field_name, field.span())?;
+    let fn_doc = setter.setter_fn_doc(struct_name, field_name, index, doc);
+
+    let vis = setter.vis();
+    let mut methods = Vec::with_capacity(2);
+    if let Some(ref validator) = setter.try_with {
+        let error = setter.try_error.as_ref().ok_or_else(|| {
+            Error::new(
+                field.span(),
+                "`try_with` setter argument requires error type to be provided with \
+                 `#[setter(try_error = \"...\")]`",
+            )
+        })?;
+        let (ret, ok) = if setter.chain {
+            (quote! { &mut Self }, quote! { self })
+        } else {
+            (quote! { () }, quote! { () })
+        };
+        methods.push(quote_spanned! { field.span() =>
+            #fn_doc
+            #[inline]
+            #vis fn #fn_name(&mut self, value: #arg_ty) -> ::core::result::Result<#ret, #error> {
+                let value = #value;
+                #validator(&value)?;
+                self.#field_access = value;
+                ::core::result::Result::Ok(#ok)
+            }
+        });
+    } else if setter.chain {
+        methods.push(quote_spanned! { field.span() =>
+            #fn_doc
+            #[inline]
+            #vis fn #fn_name(&mut self, value: #arg_ty) -> &mut Self {
+                self.#field_access = #value;
+                self
+            }
+        });
+    } else {
+        methods.push(quote_spanned! { field.span() =>
+            #fn_doc
+            #[inline]
+            #vis fn #fn_name(&mut self, value: #arg_ty) {
+                self.#field_access = #value;
+            }
+        });
+    }
+
+    if let Some(ref prefix) = setter.build {
+        let fn_name = setter.setter_fn_ident(prefix, field_name, field.span())?;
+        let fn_doc = format!(
+            "Method consuming [`{}`] and returning it with new value assigned to [`{}::{}`] \
+             field.\n",
+            struct_name,
+            struct_name,
+            field_name
+                .map(Ident::to_string)
+                .unwrap_or_else(|| index.to_string())
+        );
+        if let Some(ref validator) = setter.try_with {
+            let error = &setter.try_error;
+            methods.push(quote_spanned! { field.span() =>
+                #[doc = #fn_doc]
+                #[inline]
+                #vis fn #fn_name(mut self, value: #arg_ty) -> ::core::result::Result<Self, #error> {
+                    let value = #value;
+                    #validator(&value)?;
+                    self.#field_access = value;
+                    ::core::result::Result::Ok(self)
+                }
+            });
+        } else {
+            methods.push(quote_spanned! { field.span() =>
+                #[doc = #fn_doc]
+                #[inline]
+                #[must_use]
+                #vis fn #fn_name(mut self, value: #arg_ty) -> Self {
+                    self.#field_access = #value;
+                    self
+                }
+            });
+        }
+    }
+
+    if let Some(ref prefix) = setter.replace {
+        let fn_name = setter.setter_fn_ident(prefix, field_name, field.span())?;
+        let fn_doc = format!(
+            "Method assigning new value to [`{}::{}`] field and returning the previous one.\n",
+            struct_name,
+            field_name
+                .map(Ident::to_string)
+                .unwrap_or_else(|| index.to_string())
+        );
+        if let Some(ref validator) = setter.try_with {
+            let error = &setter.try_error;
+            methods.push(quote_spanned! { field.span() =>
+                #[doc = #fn_doc]
+                #[inline]
+                #vis fn #fn_name(&mut self, value: #arg_ty) -> ::core::result::Result<#ty, #error> {
+                    let value = #value;
+                    #validator(&value)?;
+                    ::core::result::Result::Ok(::core::mem::replace(&mut self.#field_access, value))
+                }
+            });
+        } else {
+            methods.push(quote_spanned! { field.span() =>
+                #[doc = #fn_doc]
+                #[inline]
+                #vis fn #fn_name(&mut self, value: #arg_ty) -> #ty {
+                    ::core::mem::replace(&mut self.#field_access, #value)
+                }
+            });
+        }
+    }
+
+    // Element-level methods change the collection in place, so they can't check
+    // the new value of the field before it is assigned
+    if let (true, Some(arg)) = (
+        setter.try_with.is_some(),
+        [("push", setter.push), ("insert", setter.insert)]
+            .iter()
+            .find(|(_, used)| *used)
+            .map(|(arg, _)| arg),
+    ) {
+        return Err(Error::new(
+            field.span(),
+            format!(
+                "`try_with` setter argument can't be combined with `{}`, which doesn't validate \
+                 the value",
+                arg
+            ),
+        ));
+    }
+
+    if setter.push {
+        let item_ty = type_last_segment(ty)
+            .filter(|segment| segment.ident == "Vec")
+            .and_then(|segment| match generic_type_args(segment)[..] {
+                [item] => Some(item),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                Error::new_spanned(ty, "`push` setter argument requires field of `Vec<_>` type")
+            })?;
+        let fn_name = setter.setter_fn_ident(
+            &LitStr::new("push_", field.span()),
+            field_name,
+            field.span(),
+        )?;
+        let fn_doc = format!(
+            "Method appending item to the end of [`{}::{}`] field.\n",
+            struct_name,
+            field_name
+                .map(Ident::to_string)
+                .unwrap_or_else(|| index.to_string())
+        );
+        methods.push(setter.collection_fn(
+            fn_doc,
+            fn_name,
+            quote! { item: #item_ty },
+            quote! { self.#field_access.push(item); },
+        ));
+    }
+
+    if setter.insert {
+        let (key_ty, value_ty, _) = map_key_value(ty).ok_or_else(|| {
+            Error::new_spanned(
+                ty,
+                "`insert` setter argument requires field of `HashMap<_, _>` type",
+            )
+        })?;
+        let fn_name = setter.setter_fn_ident(
+            &LitStr::new("insert_", field.span()),
+            field_name,
+            field.span(),
+        )?;
+        let fn_doc = format!(
+            "Method inserting key-value pair into [`{}::{}`] field, replacing the value for the \
+             key if it is already present.\n",
+            struct_name,
+            field_name
+                .map(Ident::to_string)
+                .unwrap_or_else(|| index.to_string())
+        );
+        methods.push(setter.collection_fn(
+            fn_doc,
+            fn_name,
+            quote! { key: #key_ty, value: #value_ty },
+            quote! { self.#field_access.insert(key, value); },
+        ));
+    }
+
+    Ok(methods)
+}
@@ -15,9 +15,18 @@
 
 #![allow(dead_code)]
 
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::iter::FromIterator;
+
+use amplify_syn::{ArgValue, ArgValueReq, AttrReq, ParametrizedAttr, ValueClass};
+use proc_macro2::Span;
 use syn::punctuated::IntoIter;
 use syn::spanned::Spanned;
-use syn::{Attribute, DeriveInput, Ident, Lit, Meta, MetaNameValue, NestedMeta, Path, Result};
+use syn::{
+    Attribute, DeriveInput, GenericArgument, Ident, Lit, LitStr, Meta, MetaNameValue, NestedMeta,
+    Path, PathArguments, PathSegment, Result, Type, TypePath,
+};
 
 /// Macro producing `Result::Err` with [`syn::Error`] containing span
 /// information from `$attr` (first) argument and formatted string describing
@@ -39,6 +48,74 @@ macro_rules! attr_err {
     };
 }
 
+/// Parses `#[accessor(...)]` attribute of a type or a field, holding the
+/// arguments shared by `Getters` and `Setters` derives. The arguments are used
+/// by both derives unless they are overridden by the `#[getter(...)]` or
+/// `#[setter(...)]` attribute of the same level.
+pub(crate) fn accessor_args(
+    attrs: &[Attribute],
+    global: bool,
+) -> Result<HashMap<String, ArgValue>> {
+    let mut attr = ParametrizedAttr::with("accessor", attrs)?;
+    let mut map = HashMap::from_iter(vec![
+        ("prefix", ArgValueReq::Optional(ValueClass::str())),
+        ("vis", ArgValueReq::Optional(ValueClass::str())),
+    ]);
+    if !global {
+        map.insert("skip", ArgValueReq::Prohibited);
+        map.insert("base_name", ArgValueReq::Optional(ValueClass::str()));
+    }
+    attr.check(AttrReq::with(map))?;
+    Ok(attr.args)
+}
+
+/// Adds the shared arguments of `#[accessor(...)]` attribute to the arguments
+/// of the `#[getter(...)]` or `#[setter(...)]` attribute of the same level,
+/// which take precedence over them. Shared `prefix` is not added, since it is
+/// combined with the prefix of the derive by [`accessor_prefix`].
+pub(crate) fn merge_accessor_args(
+    args: &mut HashMap<String, ArgValue>,
+    accessor: &HashMap<String, ArgValue>,
+) {
+    for (name, value) in accessor {
+        if name != "prefix" {
+            args.entry(name.clone()).or_insert_with(|| value.clone());
+        }
+    }
+}
+
+/// Composes method prefix of the field out of the type-level prefix of the
+/// derive (or its `default` one) followed by the shared prefix given at field
+/// or, if absent, at type level. Returns `None` if there is no shared prefix
+/// or if the `local` (field-level) arguments of the derive have own prefix,
+/// which replaces the composed one.
+pub(crate) fn accessor_prefix(
+    global: &HashMap<String, ArgValue>,
+    local: &HashMap<String, ArgValue>,
+    default: &str,
+    field_accessor: &HashMap<String, ArgValue>,
+    type_accessor: &HashMap<String, ArgValue>,
+) -> Result<Option<ArgValue>> {
+    if local.contains_key("prefix") {
+        return Ok(None);
+    }
+    let shared: LitStr = match field_accessor
+        .get("prefix")
+        .or_else(|| type_accessor.get("prefix"))
+    {
+        Some(shared) => shared.clone().try_into()?,
+        None => return Ok(None),
+    };
+    let base = global
+        .get("prefix")
+        .map(|a| a.clone().try_into())
+        .transpose()?
+        .map(|lit: LitStr| lit.value())
+        .unwrap_or_else(|| default.to_owned());
+    let prefix = LitStr::new(&format!("{}{}", base, shared.value()), shared.span());
+    Ok(Some(ArgValue::Literal(Lit::Str(prefix))))
+}
+
 pub(crate) fn get_amplify_crate(input: &DeriveInput) -> Path {
     let name = "amplify_crate";
     let example = "#[amplify_crate(amplify_crate_path)]";
@@ -159,3 +236,56 @@ pub fn nested_one_named_value(
     })
     .transpose()
 }
+
+/// Constructs identifier from the composed name, using raw identifier for
+/// keywords (like `type`). Returns `None` if the name is not a valid Rust
+/// identifier, including `_` and the path keywords which can't be raw.
+pub(crate) fn try_ident(name: &str, span: Span) -> Option<Ident> {
+    if syn::parse_str::<Ident>(name).is_ok() {
+        Some(Ident::new(name, span))
+    } else if matches!(name, "_" | "self" | "Self" | "super" | "crate") {
+        None
+    } else if syn::parse_str::<Ident>(&format!("r#{}", name)).is_ok() {
+        Some(Ident::new_raw(name, span))
+    } else {
+        None
+    }
+}
+
+/// Returns last path segment for a type given by a path (like `Vec<u8>` or
+/// `std::option::Option<T>`), ignoring any grouping/parentheses around it.
+pub(crate) fn type_last_segment(ty: &Type) -> Option<&PathSegment> {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last(),
+        Type::Group(group) => type_last_segment(&group.elem),
+        Type::Paren(paren) => type_last_segment(&paren.elem),
+        _ => None,
+    }
+}
+
+/// Returns list of generic type arguments for a path segment (like `K` and `V`
+/// in `HashMap<K, V>`), skipping lifetimes and other non-type arguments
+pub(crate) fn generic_type_args(segment: &PathSegment) -> Vec<&Type> {
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns key, value and (optional) hasher types of a `HashMap<K, V>` or
+/// `HashMap<K, V, S>` type
+pub(crate) fn map_key_value(ty: &Type) -> Option<(&Type, &Type, Option<&Type>)> {
+    let segment = type_last_segment(ty).filter(|segment| segment.ident == "HashMap")?;
+    match generic_type_args(segment)[..] {
+        [key, value] => Some((key, value, None)),
+        [key, value, hasher] => Some((key, value, Some(hasher))),
+        _ => None,
+    }
+}
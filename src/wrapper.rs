@@ -1058,7 +1058,7 @@ pub(crate) fn inner_mut(input: DeriveInput) -> Result<TokenStream2> {
     })
 }
 
-fn get_params(input: &DeriveInput) -> Result<(TokenStream2, Type)> {
+pub(crate) fn get_params(input: &DeriveInput) -> Result<(TokenStream2, Type)> {
     let data = match input.data {
         Data::Struct(ref data) => data,
         Data::Enum(_) => {
@@ -274,14 +274,14 @@ impl DataType {
                 Span::call_site(),
                 format!(
                     "deriving `{}` is not supported in unions",
-                    trait_name.to_token_stream().to_string()
+                    trait_name.to_token_stream()
                 ),
             )),
             DataInner::Uninhabited => Err(syn::Error::new(
                 Span::call_site(),
                 format!(
                     "deriving `{}` is not supported for uninhabited enums",
-                    trait_name.to_token_stream().to_string()
+                    trait_name.to_token_stream()
                 ),
             )),
         }?;
@@ -62,6 +62,11 @@ impl Parse for MetaArg {
     fn parse(input: &ParseBuffer) -> Result<Self> {
         if input.peek2(Token![=]) {
             input.parse().map(MetaArg::NameValue)
+        } else if input.peek(Ident::peek_any) && !input.peek(syn::Ident) && !input.peek2(Token![::])
+        {
+            // Keywords (like `const`) can't be parsed as paths, but are allowed as verbatim
+            // arguments
+            Ident::parse_any(input).map(|ident| MetaArg::Path(Path::from(ident)))
         } else if input.peek(Ident::peek_any) ||
             input.peek(Token![::]) && input.peek3(Ident::peek_any)
         {
@@ -97,9 +102,15 @@ pub struct MetaArgNameValue {
 
 impl Parse for MetaArgNameValue {
     fn parse(input: &ParseBuffer) -> Result<Self> {
-        let path: Path = input.parse()?;
+        let name = if input.peek(Ident::peek_any) && !input.peek(syn::Ident) {
+            // Keywords (like `trait`) can't be parsed as paths, but are allowed as argument names
+            Ident::parse_any(input)?
+        } else {
+            let path: Path = input.parse()?;
+            path.get_ident().ok_or(Error::ArgNameMustBeIdent)?.clone()
+        };
         Ok(MetaArgNameValue {
-            name: path.get_ident().ok_or(Error::ArgNameMustBeIdent)?.clone(),
+            name,
             eq_token: input.parse()?,
             value: input.parse()?,
         })
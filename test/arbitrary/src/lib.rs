@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters, Clone, PartialEq, Eq, Debug)]
+#[getter(arbitrary_ctor = "arbitrary")]
+pub struct Header {
+    version: u8,
+    flags: u16,
+    payload: Vec<u8>,
+}
+
+#[derive(Getters, Clone, PartialEq, Eq, Debug)]
+#[getter(arbitrary_ctor = "arbitrary")]
+pub struct Pair<T>(#[getter(base_name = "first")] T, #[getter(base_name = "second")] T);
+
+#[derive(Getters, Clone, PartialEq, Eq, Debug)]
+#[getter(arbitrary_ctor = "arbitrary")]
+pub struct Empty {}
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod tests {
+    use arbitrary::Unstructured;
+
+    use super::*;
+
+    #[test]
+    fn fuzzed_instance() {
+        let data = [1u8, 0x34, 0x12, 2, 0xAA, 0xBB, 0xCC];
+        let header = Header::from_unstructured(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(header.version(), &1);
+        assert_eq!(Header::from_unstructured(&mut Unstructured::new(&data)).unwrap(), header);
+
+        let pair = Pair::<u8>::from_unstructured(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(pair.first(), &1);
+        assert_eq!(pair.second(), &0x34);
+
+        assert_eq!(Empty::from_unstructured(&mut Unstructured::new(&[])).unwrap(), Empty {});
+    }
+}
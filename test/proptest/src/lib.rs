@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters, Clone, PartialEq, Eq, Debug)]
+#[getter(proptest_strategy = "proptest", as_copy)]
+pub struct Header {
+    version: u8,
+    flags: u16,
+    #[getter(as_ref)]
+    payload: Vec<u8>,
+}
+
+#[derive(Getters, Clone, PartialEq, Eq, Debug)]
+#[getter(proptest_strategy = "proptest")]
+pub struct Pair<T>(#[getter(base_name = "first")] T, #[getter(base_name = "second")] T);
+
+#[derive(Getters, Clone, PartialEq, Eq, Debug)]
+#[getter(proptest_strategy = "proptest")]
+pub struct Empty {}
+
+#[cfg(all(test, feature = "proptest"))]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn generated_header(header in Header::strategy()) {
+            let copy = header.clone();
+            prop_assert_eq!(copy.version(), header.version());
+            prop_assert_eq!(copy.flags(), header.flags());
+            prop_assert_eq!(copy.payload(), header.payload());
+        }
+
+        #[test]
+        fn generated_pair(pair in Pair::<bool>::strategy()) {
+            prop_assert_eq!(pair.first(), &pair.0);
+            prop_assert_eq!(pair.second(), &pair.1);
+        }
+
+        #[test]
+        fn generated_empty(empty in Empty::strategy()) {
+            prop_assert_eq!(empty, Empty {});
+        }
+    }
+}
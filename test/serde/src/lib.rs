@@ -0,0 +1,48 @@
+// Getter names follow serde casing, which is not snake case
+#![allow(non_snake_case)]
+
+#[macro_use]
+extern crate amplify_derive;
+#[macro_use]
+extern crate serde;
+
+#[derive(Getters, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+#[getter(as_copy, follow_serde_rename)]
+pub struct Limits {
+    max_peers: u16,
+    #[getter(as_clone)]
+    node_alias: String,
+}
+
+#[derive(Getters, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all(serialize = "SCREAMING_SNAKE_CASE", deserialize = "lowercase"))]
+#[getter(as_copy, follow_serde_rename)]
+pub struct Timeouts {
+    read_secs: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn getter_names_follow_serde_case() {
+        let limits = Limits {
+            max_peers: 8,
+            node_alias: String::from("alice"),
+        };
+        assert_eq!(limits.maxPeers(), 8);
+        assert_eq!(limits.nodeAlias(), "alice");
+
+        let json = serde_json::to_string(&limits).unwrap();
+        assert_eq!(json, r#"{"maxPeers":8,"nodeAlias":"alice"}"#);
+    }
+
+    #[test]
+    fn serialization_rule_is_used() {
+        let timeouts = Timeouts { read_secs: 30 };
+        assert_eq!(timeouts.READ_SECS(), 30);
+        assert_eq!(serde_json::to_string(&timeouts).unwrap(), r#"{"READ_SECS":30}"#);
+    }
+}
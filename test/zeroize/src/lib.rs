@@ -0,0 +1,41 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters, Clone, PartialEq, Eq, Debug)]
+pub struct Credentials {
+    login: String,
+    #[getter(zeroize = "zeroize", skip)]
+    password: String,
+    #[getter(zeroize = "zeroize")]
+    key: [u8; 4],
+}
+
+#[derive(Getters, Clone, PartialEq, Eq, Debug)]
+#[getter(zeroize_all = "zeroize")]
+pub struct Secret(#[getter(base_name = "seed")] Vec<u8>, #[getter(base_name = "nonce")] u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marked_fields_are_zeroized() {
+        let mut credentials = Credentials {
+            login: String::from("admin"),
+            password: String::from("secret"),
+            key: [1, 2, 3, 4],
+        };
+        credentials.zeroize();
+        assert_eq!(credentials.login(), "admin");
+        assert!(credentials.password.is_empty());
+        assert_eq!(credentials.key(), &[0; 4]);
+    }
+
+    #[test]
+    fn all_fields_are_zeroized() {
+        let mut secret = Secret(vec![1, 2, 3], 42);
+        secret.zeroize();
+        assert!(secret.seed().is_empty());
+        assert_eq!(secret.nonce(), &0);
+    }
+}
@@ -0,0 +1,110 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Files in `tests/build` must compile without warnings against the macro
+//! library under each of the configurations given below, with all the used
+//! configuration options declared, as cargo does for the crates using the
+//! macros.
+
+mod common;
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+/// Compiles mock of the dependency used by the derived code from
+/// `tests/build/mock`, returning `--extern` argument value for it
+fn mock(name: &str) -> String {
+    let out_dir = env::temp_dir().join("amplify_derive_build");
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let output = Command::new(rustc)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--edition", "2021", "--crate-type", "rlib", "--crate-name", name])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg(
+            Path::new("tests/build/mock")
+                .join(name)
+                .with_extension("rs"),
+        )
+        .output()
+        .expect("rustc is available");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    format!("{}={}", name, out_dir.join(format!("lib{}.rlib", name)).display())
+}
+
+/// Compiles the library crate from `tests/build` with the provided extra
+/// `rustc` arguments, denying warnings
+fn build(file: &str, args: &[&str]) {
+    let lib = common::macro_lib();
+    let out_dir = env::temp_dir().join("amplify_derive_build");
+    let output = common::rustc(&lib, &out_dir)
+        .args(["--crate-type", "lib", "--emit", "metadata", "-D", "warnings"])
+        .args(["--check-cfg", "cfg()"])
+        .args(args)
+        .arg(Path::new("tests/build").join(file))
+        .output()
+        .expect("rustc is available");
+    assert!(
+        output.status.success(),
+        "{} fails to build with {:?}:\n{}",
+        file,
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn no_tarpaulin() {
+    let check_cfg = ["--check-cfg", "cfg(coverage_nightly, tarpaulin)"];
+    build("no_tarpaulin.rs", &check_cfg);
+    build("no_tarpaulin.rs", &[&check_cfg[..], &["--cfg", "tarpaulin"]].concat());
+}
+
+#[test]
+fn arbitrary_ctor() {
+    let check_cfg = ["--check-cfg", r#"cfg(feature, values("fuzzing"))"#];
+    build("arbitrary_ctor.rs", &check_cfg);
+    let arbitrary = mock("arbitrary");
+    let enabled = ["--cfg", r#"feature="fuzzing""#, "--extern", &arbitrary];
+    build("arbitrary_ctor.rs", &[&check_cfg[..], &enabled].concat());
+}
+
+#[test]
+fn proptest_strategy() {
+    let check_cfg = ["--check-cfg", r#"cfg(feature, values("proptest"))"#];
+    build("proptest_strategy.rs", &check_cfg);
+    let proptest = mock("proptest");
+    let enabled = ["--cfg", r#"feature="proptest""#, "--extern", &proptest];
+    build("proptest_strategy.rs", &[&check_cfg[..], &enabled].concat());
+}
+
+#[test]
+fn zeroize_all() {
+    let check_cfg = ["--check-cfg", r#"cfg(feature, values("secrets"))"#];
+    build("zeroize_all.rs", &check_cfg);
+    let zeroize = mock("zeroize");
+    let enabled = ["--cfg", r#"feature="secrets""#, "--extern", &zeroize];
+    build("zeroize_all.rs", &[&check_cfg[..], &enabled].concat());
+}
+
+#[test]
+fn trace() {
+    let check_cfg = ["--check-cfg", r#"cfg(feature, values("logging"))"#];
+    build("trace.rs", &check_cfg);
+    let log = mock("log");
+    let enabled = ["--cfg", r#"feature="logging""#, "--extern", &log];
+    build("trace.rs", &[&check_cfg[..], &enabled].concat());
+}
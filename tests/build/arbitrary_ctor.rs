@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(arbitrary_ctor = "fuzzing")]
+pub struct Gated {
+    #[getter(as_copy)]
+    id: u8,
+}
+
+#[cfg(feature = "fuzzing")]
+#[derive(Getters)]
+#[getter(arbitrary_ctor)]
+pub struct Unconditional(#[getter(as_copy, base_name = "id")] u8);
+
+#[cfg(feature = "fuzzing")]
+pub fn fuzz(data: &[u8]) -> Option<(u8, u8)> {
+    let mut u = arbitrary::Unstructured(data);
+    let gated = Gated::from_unstructured(&mut u).ok()?;
+    let unconditional = Unconditional::from_unstructured(&mut u).ok()?;
+    Some((gated.id(), unconditional.id()))
+}
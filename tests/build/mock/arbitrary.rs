@@ -0,0 +1,19 @@
+//! Minimal API of `arbitrary` crate used by the derived code
+
+pub struct Unstructured<'a>(pub &'a [u8]);
+
+pub struct Error;
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+pub trait Arbitrary<'a>: Sized {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self>;
+}
+
+impl<'a> Arbitrary<'a> for u8 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (first, rest) = u.0.split_first().ok_or(Error)?;
+        u.0 = rest;
+        Ok(*first)
+    }
+}
@@ -0,0 +1,65 @@
+//! Minimal API of `proptest` crate used by the derived code
+
+pub mod strategy {
+    use core::fmt::Debug;
+
+    pub trait Strategy {
+        type Value: Debug;
+
+        fn generate(&self) -> Self::Value;
+
+        fn prop_map<O: Debug, F: Fn(Self::Value) -> O>(self, fun: F) -> Map<Self, F>
+        where
+            Self: Sized,
+        {
+            Map(self, fun)
+        }
+    }
+
+    pub struct Map<S, F>(S, F);
+
+    impl<S: Strategy, O: Debug, F: Fn(S::Value) -> O> Strategy for Map<S, F> {
+        type Value = O;
+
+        fn generate(&self) -> O { (self.1)(self.0.generate()) }
+    }
+
+    pub struct Just<T>(pub T);
+
+    impl<T: Clone + Debug> Strategy for Just<T> {
+        type Value = T;
+
+        fn generate(&self) -> T { self.0.clone() }
+    }
+
+    impl<A: Strategy, B: Strategy> Strategy for (A, B) {
+        type Value = (A::Value, B::Value);
+
+        fn generate(&self) -> Self::Value { (self.0.generate(), self.1.generate()) }
+    }
+}
+
+pub mod arbitrary {
+    use core::fmt::Debug;
+    use core::marker::PhantomData;
+
+    use crate::strategy::Strategy;
+
+    pub trait Arbitrary: Sized + Debug {
+        fn arbitrary() -> Self;
+    }
+
+    impl Arbitrary for u8 {
+        fn arbitrary() -> u8 { 7 }
+    }
+
+    pub struct Any<T>(PhantomData<T>);
+
+    impl<T: Arbitrary> Strategy for Any<T> {
+        type Value = T;
+
+        fn generate(&self) -> T { T::arbitrary() }
+    }
+
+    pub fn any<T: Arbitrary>() -> Any<T> { Any(PhantomData) }
+}
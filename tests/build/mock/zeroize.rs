@@ -0,0 +1,9 @@
+//! Minimal API of `zeroize` crate used by the derived code
+
+pub trait Zeroize {
+    fn zeroize(&mut self);
+}
+
+impl Zeroize for u8 {
+    fn zeroize(&mut self) { *self = 0; }
+}
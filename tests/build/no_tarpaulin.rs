@@ -0,0 +1,12 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters, Default)]
+#[getter(no_tarpaulin = "coverage_nightly", diff)]
+pub struct Metrics {
+    hits: u64,
+    #[getter(as_copy)]
+    misses: u64,
+}
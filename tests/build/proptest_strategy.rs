@@ -0,0 +1,20 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters, Debug)]
+#[getter(proptest_strategy = "proptest")]
+pub struct Gated {
+    #[getter(as_copy)]
+    id: u8,
+}
+
+#[cfg(feature = "proptest")]
+#[derive(Getters, Debug)]
+#[getter(proptest_strategy)]
+pub struct Unconditional(#[getter(as_copy, base_name = "id")] u8);
+
+#[cfg(feature = "proptest")]
+pub fn generate() -> (u8, u8) {
+    use proptest::strategy::Strategy;
+    (Gated::strategy().generate().id(), Unconditional::strategy().generate().id())
+}
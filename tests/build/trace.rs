@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(trace = "logging", as_copy, as_mut)]
+pub struct Gated {
+    hits: u32,
+}
+
+#[cfg(feature = "logging")]
+#[derive(Getters)]
+#[getter(trace, as_copy)]
+pub struct Unconditional(#[getter(base_name = "hits")] u32);
+
+pub fn hit(gated: &mut Gated) -> u32 {
+    *gated.hits_mut() += 1;
+    gated.hits()
+}
+
+#[cfg(feature = "logging")]
+pub fn hits(unconditional: &Unconditional) -> u32 { unconditional.hits() }
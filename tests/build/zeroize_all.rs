@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(zeroize_all = "secrets")]
+pub struct Gated {
+    #[getter(as_copy)]
+    key: u8,
+    #[getter(skip)]
+    nonce: u8,
+}
+
+#[cfg(feature = "secrets")]
+#[derive(Getters)]
+pub struct Unconditional(#[getter(as_copy, base_name = "key", zeroize)] u8);
+
+pub fn clear(gated: &mut Gated) -> (u8, u8) {
+    gated.zeroize();
+    (gated.key(), gated.nonce)
+}
+
+#[cfg(feature = "secrets")]
+pub fn clear_unconditional(unconditional: &mut Unconditional) -> u8 {
+    unconditional.zeroize();
+    unconditional.key()
+}
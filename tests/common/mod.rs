@@ -0,0 +1,67 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Helpers for the tests compiling fixture files with `rustc` against the
+//! macro library.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Locates the macro library built by cargo for the current profile. Asking
+/// cargo (which reuses the library built for the tests) instead of scanning
+/// the target directory avoids picking stale builds with other settings, as
+/// well as older versions of this crate used by the dev dependencies.
+pub fn macro_lib() -> PathBuf {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["build", "--lib", "--quiet", "--message-format", "json"])
+        .arg("--package")
+        .arg(concat!(env!("CARGO_PKG_NAME"), "@", env!("CARGO_PKG_VERSION")));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let output = command.output().expect("cargo is available");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| {
+            line.contains(r#""reason":"compiler-artifact""#) &&
+                line.contains(r#""kind":["proc-macro"]"#)
+        })
+        .find_map(|line| {
+            let filenames = line.split(r#""filenames":[""#).nth(1)?;
+            let filename = filenames.split('"').next()?;
+            Some(PathBuf::from(filename.replace(r"\\", r"\")))
+        })
+        .expect("cargo reports the macro library")
+}
+
+/// Prepares `rustc` command compiling fixtures against the macro library,
+/// with the other arguments (like the file name) to be added by the caller
+pub fn rustc(lib: &Path, out_dir: &Path) -> Command {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let mut command = Command::new(rustc);
+    command
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--edition", "2021", "--error-format", "short", "--color", "never"])
+        .arg("--extern")
+        .arg(format!("amplify_derive={}", lib.display()))
+        .arg("--out-dir")
+        .arg(out_dir);
+    command
+}
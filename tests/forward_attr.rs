@@ -0,0 +1,45 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! `non_snake_case` lint is not reported in doctests, so forwarding of the
+//! lint attributes to the getters is checked here.
+
+#![deny(non_snake_case)]
+
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters, Default)]
+#[getter(as_copy, forward_attr = "allow")]
+#[allow(non_snake_case)]
+struct Legacy {
+    #[allow(non_snake_case)]
+    maxSize: u32,
+}
+
+#[derive(Getters, Default)]
+#[getter(as_copy)]
+#[allow(non_snake_case)]
+struct Limits {
+    #[getter(forward_attr = "allow")]
+    #[allow(non_snake_case)]
+    minSize: u32,
+}
+
+#[test]
+fn forwarded_attributes_apply_to_getters() {
+    assert_eq!(Legacy::default().maxSize(), 0);
+    assert_eq!(Limits::default().minSize(), 0);
+}
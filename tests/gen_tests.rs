@@ -0,0 +1,108 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Tests generated by `#[getter(gen_tests)]` are run as a part of this test
+//! crate.
+
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters, Default)]
+#[getter(gen_tests)]
+struct Settings {
+    #[getter(as_clone, as_ref, as_mut)]
+    name: String,
+    #[getter(as_copy, as_mut)]
+    threads: u8,
+    #[getter(option, as_mut)]
+    timeout: Option<u32>,
+    #[getter(as_deref, as_str)]
+    label: String,
+    #[getter(borrow_as = "[u8]", as_slice)]
+    payload: Vec<u8>,
+    #[getter(as_copy, as_slice)]
+    digest: [u8; 4],
+    #[getter(borrow_as = "str", option)]
+    comment: Option<String>,
+    #[getter(as_copy, ret = "usize", expr = "self.peers.len()")]
+    peers: Vec<String>,
+    #[getter(skip)]
+    #[allow(dead_code)]
+    secret: Vec<u8>,
+}
+
+#[derive(Getters, Default)]
+#[getter(gen_tests, into)]
+struct Newtype {
+    inner: Vec<u8>,
+}
+
+#[derive(Getters, Default)]
+#[getter(trait = "HasEndpoint", gen_tests, as_ref, as_mut)]
+struct Endpoint {
+    host: String,
+    #[getter(as_copy)]
+    port: u16,
+}
+
+#[test]
+fn getters_are_usable() {
+    let mut settings = Settings::default();
+    *settings.threads_mut() = 4;
+    assert_eq!(settings.threads(), 4);
+    assert_eq!(settings.timeout(), None);
+    assert_eq!(settings.peers(), 0);
+    assert_eq!(Newtype::default().into_inner(), Vec::<u8>::new());
+}
+
+#[test]
+fn trait_getters_are_usable() {
+    let mut endpoint = Endpoint::default();
+    *endpoint.port_mut() = 80;
+    assert_eq!(HasEndpoint::port(&endpoint), 80);
+    assert_eq!(endpoint.host(), "");
+}
+
+#[derive(Default)]
+pub struct Id(u32);
+
+impl From<u32> for Id {
+    fn from(id: u32) -> Self { Id(id) }
+}
+
+/// Methods which can't be checked against the field value are left out of the
+/// generated tests
+#[derive(Getters, Default)]
+#[getter(gen_tests)]
+struct Untestable {
+    #[getter(pin, as_copy)]
+    pinned: u8,
+    #[getter(as_copy, as_into, into_type = "Id")]
+    id: u32,
+    #[getter(as_ref, self_ty = "&std::sync::Arc<Self>", as_mut)]
+    shared: Vec<u8>,
+    #[getter(as_ref, to_owned)]
+    name: &'static str,
+}
+
+#[test]
+fn untestable_getters_are_usable() {
+    let mut untestable = Untestable { pinned: 1, id: 2, shared: vec![3], name: "name" };
+    assert_eq!(std::pin::Pin::new(&untestable).pinned_pin().get_ref(), &1);
+    assert_eq!(untestable.id_into().0, 2);
+    assert_eq!(untestable.name_owned(), "name");
+    untestable.shared_mut().push(4);
+    assert_eq!(std::sync::Arc::new(untestable).shared(), &[3, 4]);
+}
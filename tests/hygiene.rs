@@ -0,0 +1,182 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Derived code must not depend on the prelude names, which may be shadowed at
+//! the call site, like by the common `Result` type aliases.
+
+#![allow(dead_code, non_camel_case_types)]
+
+#[macro_use]
+extern crate amplify_derive;
+
+use std::collections::HashMap;
+
+type Result<T> = std::result::Result<T, ()>;
+struct Option;
+struct Vec;
+struct String;
+struct Some;
+struct None;
+struct Ok;
+struct Err;
+struct Default;
+struct PartialEq;
+
+fn is_positive(value: &i32) -> std::result::Result<(), std::string::String> {
+    if *value > 0 {
+        return std::result::Result::Ok(());
+    }
+    std::result::Result::Err(std::string::String::from("must be positive"))
+}
+
+#[derive(Getters, Setters, Debug)]
+#[getter(validate, validate_all, snapshot, diff, field_enum)]
+#[getter(as_result = "value, error")]
+struct Record {
+    #[getter(validator = "is_positive")]
+    #[setter(try_with = "is_positive", try_error = "std::string::String")]
+    count: i32,
+    #[getter(option, is_empty)]
+    tags: std::option::Option<std::vec::Vec<u8>>,
+    #[getter(entry = "_entry")]
+    index: HashMap<u8, std::string::String>,
+    #[getter(as_ref, set_if_changed)]
+    name: std::string::String,
+    value: std::option::Option<u8>,
+    error: std::option::Option<u8>,
+}
+
+#[derive(Getters)]
+enum Either {
+    Left(u8),
+    Right(u16),
+}
+
+#[derive(Getters)]
+#[getter(try_into_inner, validate)]
+struct Wrapped(#[getter(base_name = "inner", validator = "is_positive")] i32);
+
+#[derive(Debug, Display, Error)]
+#[display("failure")]
+struct Failure {
+    #[source]
+    cause: std::io::Error,
+}
+
+#[test]
+fn derived_code_ignores_shadowed_prelude() {
+    let mut record = Record {
+        count: 1,
+        tags: std::option::Option::None,
+        index: HashMap::new(),
+        name: std::string::String::from("name"),
+        value: std::option::Option::Some(1),
+        error: std::option::Option::None,
+    };
+    assert!(record.validate().is_ok());
+    assert!(record.validate_all().is_ok());
+    assert!(record.tags_is_empty());
+    assert_eq!(record.result(), std::result::Result::Ok(&1));
+    assert!(record.set_count(0).is_err());
+    record.index_entry(1).push('x');
+    assert!(record.name_set_if_changed(std::string::String::from("other")));
+    assert_eq!(record.snapshot().len(), 6);
+    assert_eq!(Either::Left(1).as_left(), std::option::Option::Some(&1));
+    assert_eq!(Wrapped(5).try_into_inner().unwrap(), 5);
+    let failure = Failure {
+        cause: std::io::Error::new(std::io::ErrorKind::Other, "cause"),
+    };
+    assert!(std::error::Error::source(&failure).is_some());
+}
+
+mod no_prelude {
+    #![no_implicit_prelude]
+
+    #[derive(::amplify_derive::Getters)]
+    #[getter(as_mut)]
+    pub struct Settings {
+        #[getter(as_clone)]
+        pub name: ::std::string::String,
+        #[getter(as_copy)]
+        pub port: u16,
+        #[getter(as_deref)]
+        pub nick: ::std::boxed::Box<u8>,
+        #[getter(as_str)]
+        pub title: ::std::string::String,
+        #[getter(as_slice)]
+        pub peers: ::std::vec::Vec<u16>,
+        #[getter(as_into, into_type = "u64")]
+        pub limit: u32,
+        #[getter(option)]
+        pub extra: ::core::option::Option<u8>,
+    }
+
+    #[derive(::amplify_derive::Getters)]
+    pub struct Port(#[getter(as_copy, into, base_name = "number")] pub u16);
+
+    pub fn is_positive(value: &i32) -> ::core::result::Result<(), ::std::string::String> {
+        if *value > 0 {
+            return ::core::result::Result::Ok(());
+        }
+        ::core::result::Result::Err(::std::borrow::ToOwned::to_owned("must be positive"))
+    }
+
+    #[derive(::amplify_derive::Getters, ::core::default::Default)]
+    #[getter(validate, validate_all, gen_tests)]
+    pub struct Checked {
+        #[getter(as_copy, validator = "is_positive")]
+        pub count: i32,
+        #[getter(as_clone, as_mut)]
+        pub name: ::std::string::String,
+        #[getter(option)]
+        pub extra: ::core::option::Option<u8>,
+    }
+
+    #[derive(::amplify_derive::Getters)]
+    #[getter(as_clone, as_deref)]
+    pub enum Label {
+        Text(::std::string::String),
+        Boxed(::std::boxed::Box<u8>),
+    }
+}
+
+#[test]
+fn derived_code_compiles_without_implicit_prelude() {
+    let mut settings = no_prelude::Settings {
+        name: std::string::String::from("node"),
+        port: 80,
+        nick: std::boxed::Box::new(1),
+        title: std::string::String::from("title"),
+        peers: vec![1, 2],
+        limit: 10,
+        extra: std::option::Option::None,
+    };
+    assert_eq!(settings.name(), "node");
+    assert_eq!(settings.nick_deref(), &1);
+    assert_eq!(settings.title_str(), "title");
+    assert_eq!(settings.peers_slice(), &[1, 2]);
+    assert_eq!(settings.limit_into(), 10u64);
+    assert_eq!(settings.extra(), std::option::Option::None);
+    *settings.port_mut() = 8080;
+    assert_eq!(settings.port(), 8080);
+    assert_eq!(no_prelude::Port(22).into_number(), 22);
+    let label = no_prelude::Label::Text(std::string::String::from("label"));
+    assert_eq!(label.as_text(), std::option::Option::Some(std::string::String::from("label")));
+    assert_eq!(label.as_text_deref(), std::option::Option::Some("label"));
+    let checked = no_prelude::Checked::default();
+    let error = checked.validate().unwrap_err();
+    assert_eq!(error.to_string(), "invalid value of `count` field: must be positive");
+    assert_eq!(checked.validate_all().unwrap_err().len(), 1);
+}
@@ -0,0 +1,57 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Warnings of experimental getters are printed to stderr, so the test re-runs
+//! itself in a child process to capture them.
+
+#[macro_use]
+extern crate amplify_derive;
+
+use std::env;
+use std::process::Command;
+
+#[derive(Getters, Default)]
+#[getter(as_copy)]
+struct Settings {
+    #[getter(stability = "experimental")]
+    threads: u8,
+    #[getter(stability = "stable")]
+    timeout: u32,
+}
+
+const CHILD_ENV: &str = "AMPLIFY_DERIVE_STABILITY_CHILD";
+
+#[test]
+fn experimental_getter_warns_once() {
+    if env::var_os(CHILD_ENV).is_some() {
+        let settings = Settings::default();
+        for _ in 0..3 {
+            assert_eq!(settings.threads(), 0);
+            assert_eq!(settings.timeout(), 0);
+        }
+        return;
+    }
+
+    let output = Command::new(env::current_exe().unwrap())
+        .args(["experimental_getter_warns_once", "--exact", "--nocapture"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr.matches("`Settings::threads` is an experimental API").count(), 1);
+    assert!(!stderr.contains("Settings::timeout"));
+}
@@ -0,0 +1,77 @@
+// Rust language amplification derive library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2019-2020 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Each `tests/ui/*.rs` file must fail to compile with the errors (messages and
+//! their locations) listed in the `.stderr` file of the same name. The files
+//! are compiled with `rustc` against the macro library built for this test;
+//! run with `UI_OVERWRITE=1` to regenerate the `.stderr` files.
+
+mod common;
+
+use std::path::Path;
+use std::{env, fs};
+
+/// Compiles the file, returning error lines in `path:line:column: message` form
+fn compile_errors(file: &Path, lib: &Path, out_dir: &Path) -> String {
+    let output = common::rustc(lib, out_dir)
+        .args(["--crate-type", "lib", "--emit", "metadata"])
+        .arg(file)
+        .output()
+        .expect("rustc is available");
+    assert!(!output.status.success(), "{} compiles, while it must fail", file.display());
+    String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .filter(|line| line.contains(": error: ") || line.contains(": error["))
+        .map(|line| format!("{}\n", line.replace('\\', "/")))
+        .collect()
+}
+
+#[test]
+fn ui() {
+    let lib = common::macro_lib();
+    let out_dir = env::temp_dir().join("amplify_derive_ui");
+    fs::create_dir_all(&out_dir).expect("output directory");
+    let overwrite = env::var_os("UI_OVERWRITE").is_some();
+
+    let mut files = fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/ui"))
+        .expect("tests/ui directory")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().map(|ext| ext == "rs").unwrap_or_default())
+        .collect::<Vec<_>>();
+    files.sort();
+    assert!(!files.is_empty(), "no ui tests found");
+
+    let mut mismatches = vec![];
+    for path in files {
+        let name = path.file_name().expect("ui test file name");
+        let file = Path::new("tests/ui").join(name);
+        let actual = compile_errors(&file, &lib, &out_dir);
+        let stderr = path.with_extension("stderr");
+        if overwrite {
+            fs::write(&stderr, &actual).expect("writable .stderr file");
+            continue;
+        }
+        let expected = fs::read_to_string(&stderr).unwrap_or_default();
+        if actual != expected {
+            mismatches.push(format!(
+                "{}:\nexpected:\n{}actual:\n{}",
+                file.display(),
+                expected,
+                actual
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "unexpected compiler errors in\n{}", mismatches.join("\n"));
+}
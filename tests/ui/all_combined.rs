@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(all, as_clone)]
+pub struct Config {
+    name: String,
+}
@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+pub struct Config {
+    #[getter(as_clone, as_copy)]
+    port: u16,
+}
@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(prefix = "get_", prefix_from_type)]
+pub struct PrefixFromType {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct DeprecatedAlias {
+    #[getter(deprecated_alias)]
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct KeepOld {
+    #[getter(keep_old)]
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct RenameBaseName {
+    #[getter(rename = "title", base_name = "label")]
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct AsIntoWithoutType {
+    #[getter(as_into)]
+    id: u32,
+}
+
+#[derive(Getters)]
+pub struct IntoTypeWithoutAsInto {
+    #[getter(into_type = "u64")]
+    id: u32,
+}
+
+#[derive(Getters)]
+pub struct ConstExperimental {
+    #[getter(as_copy, const, stability = "experimental")]
+    id: u32,
+}
+
+#[derive(Getters)]
+pub struct ConstTrace {
+    #[getter(as_copy, const, trace)]
+    id: u32,
+}
+
+#[derive(Getters)]
+pub struct FlattenPrefix {
+    #[getter(flatten_prefix = "inner_")]
+    inner: String,
+}
+
+#[derive(Getters)]
+pub struct RetWithoutExpr {
+    #[getter(ret = "usize")]
+    items: Vec<u8>,
+}
+
+#[derive(Getters)]
+pub struct CachedWithoutExpr {
+    #[getter(cached = "self.cache")]
+    items: Vec<u8>,
+}
+
+#[derive(Getters)]
+pub struct MainAndClone {
+    #[getter(main, as_clone)]
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct ConstAsRef {
+    #[getter(const, as_ref)]
+    id: u32,
+}
+
+#[derive(Getters)]
+pub struct PinAsMut {
+    #[getter(pin, as_mut)]
+    id: u32,
+}
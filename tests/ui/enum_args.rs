@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(diff)]
+pub enum TypeLevelStructOnly {
+    Number(u8),
+}
+
+#[derive(Getters)]
+pub enum VariantLevelStructOnly {
+    #[getter(stability = "experimental")]
+    Number(u8),
+}
+
+#[derive(Getters)]
+pub enum AsStr {
+    #[getter(as_str)]
+    Number(u8),
+}
+
+#[derive(Getters)]
+pub enum AsSlice {
+    #[getter(as_slice)]
+    Number(u8),
+}
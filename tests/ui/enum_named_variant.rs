@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+pub enum Shape {
+    Circle(u32),
+    Rect { width: u32, height: u32 },
+}
@@ -0,0 +1,100 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+pub struct AsStr {
+    #[getter(as_str)]
+    id: u8,
+}
+
+#[derive(Getters)]
+pub struct AsSlice {
+    #[getter(as_slice)]
+    id: u8,
+}
+
+#[derive(Getters)]
+pub struct CachedAsMut {
+    #[getter(as_ref, as_mut, cached = "self.cache", expr = "0")]
+    id: u8,
+}
+
+#[derive(Getters)]
+pub struct MustUseAsMut {
+    #[getter(as_mut, skip_ref, must_use)]
+    id: u8,
+}
+
+#[derive(Getters)]
+pub struct IsEmpty {
+    #[getter(is_empty = "_is_empty")]
+    items: Vec<u8>,
+}
+
+#[derive(Getters)]
+pub struct Entry {
+    #[getter(entry = "_entry")]
+    items: Vec<u8>,
+}
+
+#[derive(Getters)]
+pub struct Observable {
+    #[getter(observable)]
+    id: u8,
+}
+
+#[derive(Getters)]
+#[getter(merge)]
+pub struct Merge {
+    id: u8,
+}
+
+#[derive(Getters)]
+#[getter(as_result = "value, error")]
+pub struct AsResultTypes {
+    value: u8,
+    error: Option<u8>,
+}
+
+#[derive(Getters)]
+#[getter(as_result = "value, failure")]
+pub struct AsResultUnknown {
+    value: Option<u8>,
+    error: Option<u8>,
+}
+
+#[derive(Getters)]
+#[getter(group = "pair: x, z")]
+pub struct GroupUnknown {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Getters)]
+#[getter(try_into_inner)]
+pub struct TryIntoInner {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Getters)]
+#[getter(into_parts)]
+pub struct IntoPartsCfg {
+    #[cfg(all())]
+    x: u8,
+    y: u8,
+}
+
+#[derive(Getters)]
+pub struct SwapUnknown {
+    #[getter(swap = "z")]
+    x: u8,
+    y: u8,
+}
+
+#[derive(Getters)]
+pub struct SwapSelf {
+    #[getter(swap = "x")]
+    x: u8,
+    y: u8,
+}
@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(From)]
+pub enum ExplicitFirst {
+    #[from(u8)]
+    A(u16),
+    B(u8),
+}
+
+#[derive(From)]
+pub enum ImplicitFirst {
+    A(u8),
+    #[from(u8)]
+    B(u16),
+}
+
+#[derive(From)]
+pub enum BothImplicit {
+    A(u8),
+    B(u8),
+}
@@ -0,0 +1,143 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(stability = "beta")]
+pub struct Stability {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(inline = "never")]
+pub struct Inline {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(ref_vis = "public")]
+pub struct Visibility {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(cfg = "feature =")]
+pub struct Cfg {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(rename_all = "kebab-case")]
+pub struct RenameAll {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(rename_all_fields = "trim:x")]
+pub struct RenameAllFields {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(rename_all_fields = "case:kebab")]
+pub struct RenameAllFieldsCase {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct Rename {
+    #[getter(rename = "full name")]
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(trait = "Named Getters")]
+pub struct Trait {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct SelfTy {
+    #[getter(self_ty = "&Vec<u8>")]
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(allow = "clippy::")]
+pub struct Allow {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(allow = "")]
+pub struct AllowEmpty {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct DocAlias {
+    #[getter(doc_alias = "name,,title")]
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(only = "name; title")]
+pub struct Only {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(forward_attr = "serde::rename")]
+pub struct ForwardAttr {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(delegate_deref = "len")]
+pub struct DelegateDeref {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct Flatten {
+    #[getter(flatten = "len")]
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(group = "pair x, y")]
+pub struct Group {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Getters)]
+#[getter(group = "pair: x")]
+pub struct GroupSingle {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Getters)]
+#[getter(as_result = "value")]
+pub struct AsResult {
+    value: Option<u8>,
+    error: Option<u8>,
+}
+
+#[derive(Getters)]
+#[getter(prefix = 1)]
+pub struct WrongClass {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct MissingValue {
+    #[getter(as_copy, as_into, into_type)]
+    id: u32,
+}
+
+#[derive(Getters)]
+#[getter(suffix = "-x")]
+pub struct InvalidName {
+    name: String,
+}
@@ -0,0 +1,18 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(field_enum)]
+pub struct FieldEnum(#[getter(base_name = "_")] u8);
+
+#[derive(Getters)]
+pub struct SelfName {
+    #[getter(prefix = "", base_name = "self")]
+    name: String,
+}
+
+#[derive(Setters)]
+pub struct SetterName {
+    #[setter(prefix = "", base_name = "crate")]
+    name: String,
+}
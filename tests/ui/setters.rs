@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Setters)]
+pub enum Enum {
+    Number(u8),
+}
+
+#[derive(Setters)]
+pub union Union {
+    number: u8,
+}
+
+#[derive(Setters)]
+pub struct Unit;
+
+#[derive(Setters)]
+pub struct Unnamed(u8);
+
+#[derive(Setters)]
+pub struct ValueRequired {
+    #[setter(try_error)]
+    number: u8,
+}
+
+fn is_valid(_: &u8) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(Setters)]
+pub struct TryWithoutError {
+    #[setter(try_with = "is_valid")]
+    number: u8,
+}
+
+#[derive(Setters)]
+pub struct Push {
+    #[setter(push)]
+    number: u8,
+}
+
+#[derive(Setters)]
+pub struct Insert {
+    #[setter(insert)]
+    number: u8,
+}
+
+#[derive(Setters)]
+#[accessor(skip)]
+pub struct TypeLevelAccessor {
+    number: u8,
+}
+
+#[derive(Setters)]
+pub struct InvalidVis {
+    #[accessor(vis = "public")]
+    number: u8,
+}
+
+fn non_empty(items: &Vec<u8>) -> Result<(), ()> {
+    if items.is_empty() {
+        return Err(());
+    }
+    Ok(())
+}
+
+#[derive(Setters)]
+pub struct ValidatedPush {
+    #[setter(push, try_with = "non_empty", try_error = "()")]
+    items: Vec<u8>,
+}
@@ -0,0 +1,119 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(only = "title")]
+pub struct OnlyUnknown {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct TryIntoStruct {
+    #[getter(try_into)]
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct PrefixFromTupleType {
+    #[getter(prefix_from_type)]
+    pair: (u8, u8),
+}
+
+#[derive(Getters)]
+#[getter(into)]
+pub struct IntoManyFields {
+    name: String,
+    title: String,
+}
+
+#[derive(Getters)]
+pub struct BorrowTwice {
+    #[getter(borrow)]
+    name: String,
+    #[getter(borrow)]
+    title: String,
+}
+
+#[derive(Getters)]
+#[getter(no_getters, trait = "Named")]
+pub struct NoGettersTrait {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(trait = "Named", ref_vis = "pub(crate)")]
+pub struct TraitVisibility {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(trait = "Counted")]
+pub struct TraitConst {
+    #[getter(as_copy, const)]
+    count: u8,
+}
+
+fn is_valid(_: &String) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(Getters)]
+pub struct ValidatorWithoutValidate {
+    #[getter(validator = "is_valid")]
+    name: String,
+}
+
+#[derive(Getters, Hash)]
+#[getter(checksum)]
+pub struct ChecksumWithoutFields {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct ChecksumIncludeWithoutChecksum {
+    #[getter(checksum_include)]
+    name: String,
+}
+
+#[derive(Getters, Default)]
+#[getter(gen_tests)]
+pub struct GenTestsGeneric<T> {
+    value: T,
+}
+
+#[derive(Getters)]
+#[getter(ensure_send_sync)]
+pub struct EnsureSendSyncGeneric<T> {
+    value: T,
+}
+
+#[derive(Getters)]
+#[getter(repr_c_assert)]
+pub struct ReprC {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(follow_serde_rename)]
+pub struct SerdeRenameMissing {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(follow_serde_rename, rename_all = "camelCase")]
+pub struct SerdeRenameAll {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct NameClash {
+    #[getter(base_name = "title")]
+    name: String,
+    title: String,
+}
+
+#[derive(Getters)]
+pub struct SuffixClash {
+    #[getter(as_copy, as_into = "", into_type = "u64")]
+    id: u32,
+}
@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+pub struct SwapTypes {
+    #[getter(swap = "y")]
+    x: u8,
+    y: u16,
+}
@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+pub union Bits {
+    int: u32,
+    float: f32,
+}
@@ -0,0 +1,5 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+pub struct Marker;
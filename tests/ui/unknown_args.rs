@@ -0,0 +1,32 @@
+#[macro_use]
+extern crate amplify_derive;
+
+#[derive(Getters)]
+#[getter(as_clne)]
+pub struct Misspelled {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(frobnicate)]
+pub struct Unknown {
+    name: String,
+}
+
+#[derive(Getters)]
+pub struct TypeLevelOnly {
+    #[getter(diff)]
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(rename = "title")]
+pub struct FieldLevelOnly {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getter(zeta = "a", beta = "b", gamma = "c", frob)]
+pub struct SeveralUnknown {
+    name: String,
+}